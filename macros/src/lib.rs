@@ -0,0 +1,277 @@
+use std::collections::HashSet;
+
+use consensus_solver::{ConesnsusTable, Die, DieSide, Minterm, SecondQuineTable};
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// The most variables a function can have
+const MAX_VARIABLES: usize = 16;
+
+/// Minimizes a boolean function at compile time. <br>
+/// The variables are named first, the first being the most significant bit,
+/// followed by the ON-set `m(...)` and optionally the dont care set `d(...)`. <br>
+/// Expands to a minimal disjunctive expression over the variables, which have to be `bool`s in scope
+///
+/// Example: <br>
+/// `minimize!(a, b, c; m(1, 3, 5, 7) + d(2))` expands to `((c))`, preceded by a check that the variables are `bool`s
+#[proc_macro]
+pub fn minimize(input: TokenStream) -> TokenStream {
+    match Function::parse(input).and_then(|function| function.minimize()) {
+        Ok(expression) => expression,
+        Err((span, message)) => compile_error(span, &message),
+    }
+}
+
+type Error = (Span, String);
+
+/// A boolean function given by its variable names, ON-set and dont care set
+struct Function {
+    variables: Vec<Ident>,
+    on: Vec<Minterm>,
+    dont_care: Vec<Minterm>,
+}
+
+impl Function {
+    /// Parses `a, b, c; m(1, 3) + d(2)`
+    fn parse(input: TokenStream) -> Result<Self, Error> {
+        let mut tokens = input.into_iter();
+        let mut variables: Vec<Ident> = Vec::new();
+
+        loop {
+            match tokens.next() {
+                Some(TokenTree::Ident(ident)) => {
+                    if variables.iter().any(|v| v.to_string() == ident.to_string()) {
+                        return Err((ident.span(), format!("variable `{ident}` is named twice")));
+                    }
+                    variables.push(ident);
+                }
+                Some(token) => return Err((token.span(), "expected a variable name".to_string())),
+                None => return Err((Span::call_site(), "expected variable names".to_string())),
+            }
+
+            match tokens.next() {
+                Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => {}
+                Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => break,
+                Some(token) => return Err((token.span(), "expected `,` or `;`".to_string())),
+                None => {
+                    return Err((
+                        Span::call_site(),
+                        "expected `;` followed by `m(...)`".to_string(),
+                    ))
+                }
+            }
+        }
+
+        if variables.len() > MAX_VARIABLES {
+            return Err((
+                variables[MAX_VARIABLES].span(),
+                format!("at most {MAX_VARIABLES} variables are supported"),
+            ));
+        }
+
+        let mut on = None;
+        let mut dont_care = None;
+        loop {
+            let (name, span) = match tokens.next() {
+                Some(TokenTree::Ident(ident)) => (ident.to_string(), ident.span()),
+                Some(token) => {
+                    return Err((token.span(), "expected `m(...)` or `d(...)`".to_string()))
+                }
+                None => return Err((Span::call_site(), "expected `m(...)`".to_string())),
+            };
+            let set = match name.as_str() {
+                "m" => &mut on,
+                "d" => &mut dont_care,
+                _ => return Err((span, "expected `m(...)` or `d(...)`".to_string())),
+            };
+            if set.is_some() {
+                return Err((span, format!("`{name}(...)` is given twice")));
+            }
+
+            match tokens.next() {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    *set = Some(Self::parse_minterms(group, variables.len())?);
+                }
+                Some(token) => return Err((token.span(), "expected `(`".to_string())),
+                None => return Err((span, "expected `(`".to_string())),
+            }
+
+            match tokens.next() {
+                Some(TokenTree::Punct(punct)) if punct.as_char() == '+' => {}
+                Some(token) => return Err((token.span(), "expected `+` or the end".to_string())),
+                None => break,
+            }
+        }
+
+        let on = on.ok_or((Span::call_site(), "expected `m(...)`".to_string()))?;
+        let dont_care = dont_care.unwrap_or_default();
+
+        Ok(Function {
+            variables,
+            on,
+            dont_care,
+        })
+    }
+
+    /// Parses the comma separated minterms of a group, each below 2 to the power of the variable count
+    fn parse_minterms(group: Group, variables: usize) -> Result<Vec<Minterm>, Error> {
+        let mut minterms = Vec::new();
+        let mut tokens = group.stream().into_iter();
+
+        while let Some(token) = tokens.next() {
+            let minterm = match &token {
+                TokenTree::Literal(literal) => literal
+                    .to_string()
+                    .parse::<Minterm>()
+                    .map_err(|_| (literal.span(), "expected a minterm index".to_string()))?,
+                token => return Err((token.span(), "expected a minterm index".to_string())),
+            };
+            if minterm >> variables != 0 {
+                return Err((
+                    token.span(),
+                    format!("minterm {minterm} doesn't exist with {variables} variables"),
+                ));
+            }
+            minterms.push(minterm);
+
+            match tokens.next() {
+                Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => {}
+                Some(token) => return Err((token.span(), "expected `,`".to_string())),
+                None => break,
+            }
+        }
+
+        Ok(minterms)
+    }
+
+    /// Runs the consensus and quine pipeline and returns the minimal expression
+    fn minimize(&self) -> Result<TokenStream, Error> {
+        let on: HashSet<_> = self.on.iter().collect();
+        if let Some(minterm) = self.dont_care.iter().find(|minterm| on.contains(minterm)) {
+            return Err((
+                Span::call_site(),
+                format!("minterm {minterm} is both in the ON-set and the dont care set"),
+            ));
+        }
+
+        macro_rules! dispatch {
+            ($($n:literal)*) => {
+                match self.variables.len() {
+                    $($n => self.block(self.expression(&self.solve::<$n>())),)*
+                    _ => unreachable!(),
+                }
+            };
+        }
+
+        Ok(dispatch!(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16))
+    }
+
+    /// Finds a minimal cover and returns the dice as the positions fixed to a value
+    fn solve<const N: usize>(&self) -> Vec<Vec<(usize, bool)>> {
+        if self.on.is_empty() {
+            return Vec::new();
+        }
+
+        let dice = |minterms: &[Minterm]| {
+            minterms
+                .iter()
+                .map(|&m| Die::<N>::from_minterm(m))
+                .collect()
+        };
+        let mut consensus_table = ConesnsusTable::new(dice(&self.on), dice(&self.dont_care));
+        consensus_table.solve();
+        let mut quine_table = SecondQuineTable::from(&consensus_table);
+        quine_table.solve();
+
+        quine_table
+            .solution()
+            .iter()
+            .map(|die| {
+                die.content
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, side)| match side {
+                        DieSide::Zero => Some((i, false)),
+                        DieSide::One => Some((i, true)),
+                        DieSide::DontCare => None,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Wraps the expression into `{ let _: [bool; 3] = [a, b, c]; expression }`,
+    /// so all variables have to be `bool`s and count as used
+    fn block(&self, expression: TokenStream) -> TokenStream {
+        let mut variables = TokenStream::new();
+        for (i, variable) in self.variables.iter().enumerate() {
+            if i > 0 {
+                variables.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+            }
+            variables.extend([TokenTree::Ident(variable.clone())]);
+        }
+
+        let mut block: TokenStream = format!("let _: [bool; {}] =", self.variables.len())
+            .parse()
+            .unwrap();
+        block.extend([
+            TokenTree::Group(Group::new(Delimiter::Bracket, variables)),
+            TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+        ]);
+        block.extend(expression);
+
+        TokenTree::Group(Group::new(Delimiter::Brace, block)).into()
+    }
+
+    /// Builds `((!a & b) | (c))` from the products, `false` for no products and `true` for an empty product
+    fn expression(&self, products: &[Vec<(usize, bool)>]) -> TokenStream {
+        if products.is_empty() {
+            return TokenTree::Ident(Ident::new("false", Span::call_site())).into();
+        }
+        if products.iter().any(Vec::is_empty) {
+            return TokenTree::Ident(Ident::new("true", Span::call_site())).into();
+        }
+
+        let mut sum = TokenStream::new();
+        for (i, product) in products.iter().enumerate() {
+            if i > 0 {
+                sum.extend([TokenTree::Punct(Punct::new('|', Spacing::Alone))]);
+            }
+
+            let mut literals = TokenStream::new();
+            for (j, &(variable, value)) in product.iter().enumerate() {
+                if j > 0 {
+                    literals.extend([TokenTree::Punct(Punct::new('&', Spacing::Alone))]);
+                }
+                if !value {
+                    literals.extend([TokenTree::Punct(Punct::new('!', Spacing::Alone))]);
+                }
+                literals.extend([TokenTree::Ident(self.variables[variable].clone())]);
+            }
+            sum.extend([TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                literals,
+            ))]);
+        }
+
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, sum)).into()
+    }
+}
+
+/// Builds `compile_error!("message")` pointing at the span
+fn compile_error(span: Span, message: &str) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+
+    [
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(bang),
+        TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            TokenTree::Literal(message).into(),
+        )),
+    ]
+    .into_iter()
+    .collect()
+}
@@ -0,0 +1,48 @@
+use consensus_solver_macros::minimize;
+
+fn bits<const N: usize>(minterm: usize) -> [bool; N] {
+    std::array::from_fn(|i| minterm >> (N - 1 - i) & 1 == 1)
+}
+
+#[test]
+fn three_variables() {
+    for minterm in 0..8 {
+        let [a, b, c] = bits(minterm);
+        let expected = [1, 3, 5, 7].contains(&minterm);
+        assert_eq!(minimize!(a, b, c; m(1, 3, 5, 7) + d(2)), expected);
+    }
+}
+
+#[test]
+fn dont_cares() {
+    for minterm in 0..16 {
+        let [w, x, y, z] = bits(minterm);
+        let f = minimize!(w, x, y, z; m(0, 2, 5, 7, 8, 10, 13, 15) + d(1, 4));
+        if [0, 2, 5, 7, 8, 10, 13, 15].contains(&minterm) {
+            assert!(f);
+        } else if ![1, 4].contains(&minterm) {
+            assert!(!f);
+        }
+    }
+}
+
+#[test]
+fn cyclic_core() {
+    for minterm in 0..8 {
+        let [a, b, c] = bits(minterm);
+        let expected = [0, 1, 2, 5, 6, 7].contains(&minterm);
+        assert_eq!(minimize!(a, b, c; m(0, 1, 2, 5, 6, 7)), expected);
+    }
+}
+
+#[test]
+fn constants() {
+    let a = true;
+    let never = minimize!(a; m());
+    let always = minimize!(a; m(0) + d(1));
+    let reordered = minimize!(a; d(0) + m(1));
+
+    assert!(!never);
+    assert!(always);
+    assert!(reordered);
+}
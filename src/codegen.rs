@@ -0,0 +1,2 @@
+mod code_generator;
+pub use code_generator::CodeGenerator;
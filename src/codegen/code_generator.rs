@@ -0,0 +1,349 @@
+use std::fmt::Write;
+
+use crate::{Die, Minterm};
+
+/// Generates Rust and C functions evaluating a minimized cover. <br>
+/// The functions take the input as integer, the first variable being the most significant bit,
+/// and check each product with a mask and compare
+///
+/// Example: <br>
+/// `(1 0 -) + (- 1 1)` becomes `(x & 0b110) == 0b100 || (x & 0b011) == 0b011`
+pub struct CodeGenerator<const N: usize> {
+    name: String,
+    cover: Vec<Die<N>>,
+}
+
+impl<const N: usize> CodeGenerator<N> {
+    /// Creates the generator for a function with the passed name. <br>
+    /// The name has to be a valid identifier in Rust and C, that the generated tests don't use themselves
+    pub fn new<S: Into<String>>(name: S, cover: Vec<Die<N>>) -> Self {
+        let name = name.into();
+        let mut chars = name.chars();
+        let valid = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            panic!("\"{name}\" is not a valid function name");
+        }
+        if RESERVED_WORDS.contains(&name.as_str()) {
+            panic!("\"{name}\" is a reserved word in Rust or C, or used by the generated tests");
+        }
+
+        CodeGenerator { name, cover }
+    }
+
+    /// Renders a Rust `const fn` evaluating the cover
+    pub fn rust(&self) -> String {
+        let ty = Self::rust_type();
+        let products = self.products(|mask, value| {
+            format!(
+                "(x & {}) == {}",
+                Self::rust_literal(mask),
+                Self::rust_literal(value)
+            )
+        });
+
+        let mut ret = format!("/// {}\n", self.comment());
+        let _ = writeln!(ret, "pub const fn {}(x: {ty}) -> bool {{", self.name);
+        let _ = writeln!(ret, "    {}", products.join("\n        || "));
+        ret.push_str("}\n");
+        ret
+    }
+
+    /// Renders a Rust test checking the generated function against the ON and dont care dice of the function,
+    /// for every input
+    pub fn rust_test(&self, on: &[Die<N>], dont_care: &[Die<N>]) -> String {
+        let ty = Self::rust_type();
+        let name = &self.name;
+
+        let mut ret = String::from("#[test]\n");
+        let _ = writeln!(ret, "fn {name}_matches_dice() {{");
+        let _ = writeln!(
+            ret,
+            "    const ON: &[({ty}, {ty})] = &[{}];",
+            Self::rust_masks(on)
+        );
+        let _ = writeln!(
+            ret,
+            "    const DONT_CARE: &[({ty}, {ty})] = &[{}];",
+            Self::rust_masks(dont_care)
+        );
+        // shifting by all bits of the type overflows, so a function without variables gets its only input directly
+        let last = match N {
+            0 => "0".to_string(),
+            _ => format!("({ty}::MAX >> ({} - {N}))", ty.trim_start_matches('u')),
+        };
+        let _ = writeln!(ret, "    for x in 0..={last} {{");
+        ret.push_str("        let covered = |dice: &[_]| dice.iter().any(|&(mask, value)| x & mask == value);\n");
+        ret.push_str("        if covered(DONT_CARE) {\n            continue;\n        }\n");
+        let _ = writeln!(
+            ret,
+            "        assert_eq!({name}(x), covered(ON), \"{name}({{x:#b}})\");"
+        );
+        ret.push_str("    }\n}\n");
+        ret
+    }
+
+    /// Renders a C function evaluating the cover
+    pub fn c(&self) -> String {
+        let ty = Self::c_type();
+        let products = self.products(|mask, value| {
+            format!(
+                "(x & {}) == {}",
+                Self::c_literal(mask),
+                Self::c_literal(value)
+            )
+        });
+
+        let mut ret = String::from("#include <stdbool.h>\n#include <stdint.h>\n\n");
+        let _ = writeln!(ret, "/* {} */", self.comment());
+        let _ = writeln!(ret, "bool {}({ty} x) {{", self.name);
+        let _ = writeln!(ret, "    return {};", products.join("\n        || "));
+        ret.push_str("}\n");
+        ret
+    }
+
+    /// Renders a C `main` checking the generated function against the ON and dont care dice of the function,
+    /// for every input. It prints every mismatch and returns 1 if there was one
+    pub fn c_test(&self, on: &[Die<N>], dont_care: &[Die<N>]) -> String {
+        let ty = Self::c_type();
+        let name = &self.name;
+
+        let mut ret = String::from("#include <stdio.h>\n\n");
+        let _ = writeln!(
+            ret,
+            "static bool covered(const {ty} dice[][2], int count, {ty} x) {{"
+        );
+        ret.push_str("    for (int i = 0; i < count; i++) {\n");
+        ret.push_str(
+            "        if ((x & dice[i][0]) == dice[i][1]) {\n            return true;\n        }\n",
+        );
+        ret.push_str("    }\n    return false;\n}\n\n");
+
+        ret.push_str("int main(void) {\n");
+        let _ = writeln!(
+            ret,
+            "    static const {ty} on[][2] = {{{}}};",
+            Self::c_masks(on)
+        );
+        let _ = writeln!(
+            ret,
+            "    static const {ty} dont_care[][2] = {{{}}};",
+            Self::c_masks(dont_care)
+        );
+        ret.push_str("    int failed = 0;\n");
+        let _ = writeln!(ret, "    for ({ty} x = 0; x < ({ty})1 << {N}; x++) {{");
+        let _ = writeln!(
+            ret,
+            "        if (covered(dont_care, {}, x)) {{\n            continue;\n        }}",
+            dont_care.len()
+        );
+        let _ = writeln!(
+            ret,
+            "        if ({name}(x) != covered(on, {}, x)) {{",
+            on.len()
+        );
+        let _ = writeln!(
+            ret,
+            "            printf(\"{name}(%llu) is wrong\\n\", (unsigned long long)x);"
+        );
+        ret.push_str("            failed = 1;\n        }\n    }\n    return failed;\n}\n");
+        ret
+    }
+
+    /// Renders every product with the mask and value, or the constant if there is none
+    fn products<F: Fn(Minterm, Minterm) -> String>(&self, product: F) -> Vec<String> {
+        if self.cover.is_empty() {
+            return vec!["false".to_string()];
+        }
+        if self.cover.iter().any(|die| die.literal_count() == 0) {
+            return vec!["true".to_string()];
+        }
+
+        self.cover
+            .iter()
+            .map(|die| {
+                let (mask, value) = die.mask();
+                product(mask, value)
+            })
+            .collect()
+    }
+
+    fn comment(&self) -> String {
+        if self.cover.is_empty() {
+            return "0".to_string();
+        }
+        self.cover
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(" + ")
+    }
+
+    fn rust_type() -> &'static str {
+        match N {
+            0..=32 => "u32",
+            33..=64 => "u64",
+            _ => "u128",
+        }
+    }
+
+    fn rust_literal(value: Minterm) -> String {
+        format!("{:#0width$b}", value, width = N + 2)
+    }
+
+    fn rust_masks(dice: &[Die<N>]) -> String {
+        dice.iter()
+            .map(|die| {
+                let (mask, value) = die.mask();
+                format!(
+                    "({}, {})",
+                    Self::rust_literal(mask),
+                    Self::rust_literal(value)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    fn c_type() -> &'static str {
+        match N {
+            0..=31 => "uint32_t",
+            32..=63 => "uint64_t",
+            _ => panic!("C functions can only be generated for at most 63 variables"),
+        }
+    }
+
+    fn c_literal(value: Minterm) -> String {
+        match N {
+            0..=31 => format!("{value:#x}u"),
+            _ => format!("{value:#x}ull"),
+        }
+    }
+
+    fn c_masks(dice: &[Die<N>]) -> String {
+        if dice.is_empty() {
+            // C doesn't allow empty initializers before C23
+            return "{0, 1}".to_string();
+        }
+
+        dice.iter()
+            .map(|die| {
+                let (mask, value) = die.mask();
+                format!("{{{}, {}}}", Self::c_literal(mask), Self::c_literal(value))
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+/// Keywords of Rust and C, and the names the generated functions and tests use themselves
+const RESERVED_WORDS: &[&str] = &[
+    "_",
+    "abstract",
+    "_Alignas",
+    "alignas",
+    "_Alignof",
+    "alignof",
+    "as",
+    "async",
+    "_Atomic",
+    "auto",
+    "await",
+    "become",
+    "_Bool",
+    "bool",
+    "box",
+    "break",
+    "case",
+    "char",
+    "_Complex",
+    "const",
+    "constexpr",
+    "continue",
+    "count",
+    "covered",
+    "crate",
+    "default",
+    "dice",
+    "do",
+    "DONT_CARE",
+    "dont_care",
+    "double",
+    "dyn",
+    "else",
+    "enum",
+    "extern",
+    "failed",
+    "false",
+    "final",
+    "float",
+    "fn",
+    "for",
+    "gen",
+    "_Generic",
+    "goto",
+    "i",
+    "if",
+    "_Imaginary",
+    "impl",
+    "in",
+    "inline",
+    "int",
+    "let",
+    "long",
+    "loop",
+    "macro",
+    "main",
+    "match",
+    "mod",
+    "move",
+    "mut",
+    "_Noreturn",
+    "nullptr",
+    "ON",
+    "on",
+    "override",
+    "printf",
+    "priv",
+    "pub",
+    "ref",
+    "register",
+    "restrict",
+    "return",
+    "Self",
+    "self",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "_Static_assert",
+    "static_assert",
+    "struct",
+    "super",
+    "switch",
+    "_Thread_local",
+    "thread_local",
+    "trait",
+    "true",
+    "try",
+    "type",
+    "typedef",
+    "typeof",
+    "typeof_unqual",
+    "uint32_t",
+    "uint64_t",
+    "union",
+    "unsafe",
+    "unsigned",
+    "unsized",
+    "use",
+    "virtual",
+    "void",
+    "volatile",
+    "where",
+    "while",
+    "x",
+    "yield",
+];
@@ -1,4 +1,7 @@
 mod consensus_table_entry;
 pub use consensus_table_entry::ConsensusTableEntry;
+#[allow(clippy::module_inception)]
 mod consensus_table;
 pub use consensus_table::ConesnsusTable;
+mod derivation_graph;
+pub use derivation_graph::DerivationGraph;
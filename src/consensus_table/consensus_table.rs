@@ -1,8 +1,8 @@
 use std::fmt::Display;
 
-use crate::Die;
+use crate::{Cover, Die, SecondQuineTable, StaticHazard};
 
-use super::ConsensusTableEntry;
+use super::{ConsensusTableEntry, DerivationGraph};
 
 const NUM_TITLE: &str = "Num.";
 const CREATOR_TITLE: &str = "Created by";
@@ -10,11 +10,14 @@ const DIE_TITLE: &str = "Die";
 const COVERED_TITLE: &str = "Covered by";
 
 /// A consensus table used for simplifying the input function
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ConesnsusTable<const N: usize> {
     entries: Vec<ConsensusTableEntry<N>>,
 }
 
 impl<const N: usize> ConesnsusTable<N> {
+    /// Creates the table for dice that don't cover each other, like the minterms of a function. <br>
+    /// Input dice covered by another input die stay uncovered, use `from_cover` for arbitrary sums of products
     pub fn new(dice: Vec<Die<N>>, dont_care: Vec<Die<N>>) -> Self {
         let mut count = 0;
 
@@ -48,6 +51,47 @@ impl<const N: usize> ConesnsusTable<N> {
         ConesnsusTable { entries }
     }
 
+    /// Creates the table for an arbitrary sum of products. <br>
+    /// Unlike `new`, input dice that are already covered by another input die are marked as covered right away,
+    /// so only the prime implicants are left uncovered after solving
+    pub fn from_cover(dice: Vec<Die<N>>, dont_care: Vec<Die<N>>) -> Self {
+        let mut table = ConesnsusTable::new(dice, dont_care);
+        table.absorb_inputs();
+        table
+    }
+
+    /// Builds the Blake canonical form, the sum of all prime implicants, of an arbitrary sum of products. <br>
+    /// The input dice may already contain DontCares, e.g. from an already simplified expression
+    ///
+    /// Example: <br>
+    /// `(1 1 -)`, `(0 - 1)` => `(1 1 -)`, `(0 - 1)`, `(- 1 1)`
+    pub fn blake_canonical_form(dice: Vec<Die<N>>) -> Vec<Die<N>> {
+        let mut table = ConesnsusTable::from_cover(dice, Vec::new());
+        table.solve();
+        table.prime_implicants()
+    }
+
+    /// Marks all input dice, that are already covered by another input die, as covered. <br>
+    /// Of two equal dice the later one is marked
+    fn absorb_inputs(&mut self) {
+        for i in 0..self.entries.len() {
+            if self.entries[i].dont_care {
+                continue;
+            }
+
+            for j in 0..self.entries.len() {
+                if i == j || (self.entries[i].die == self.entries[j].die && j > i) {
+                    continue;
+                }
+
+                if self.entries[j].die.covers(self.entries[i].die) {
+                    self.entries[i].covered = self.entries[j].num;
+                    break;
+                }
+            }
+        }
+    }
+
     /// This function checks if an entry in the table covers the passed entry and, if it does, sets the covered attribute accordingly
     fn table_covers_die(&self, subject: &mut ConsensusTableEntry<N>) {
         for entry in &self.entries {
@@ -89,13 +133,10 @@ impl<const N: usize> ConesnsusTable<N> {
 
     /// Checks if the element at index i is covered (ignoring dont cares that cover themselves)
     fn is_covered(&self, i: usize) -> bool {
-        match self.entries[i].covered {
-            Some(val) => match self.entries[i].num {
-                Some(num) if val != num => true,
-                _ => false,
-            },
-            _ => false,
-        }
+        matches!(
+            (self.entries[i].covered, self.entries[i].num),
+            (Some(val), Some(num)) if val != num
+        )
     }
 
     pub fn solve(&mut self) {
@@ -104,6 +145,7 @@ impl<const N: usize> ConesnsusTable<N> {
             // start at the second element, and walk down the list
             while curr < self.entries.len() {
                 if self.is_covered(curr) {
+                    curr += 1;
                     continue;
                 }
 
@@ -125,8 +167,8 @@ impl<const N: usize> ConesnsusTable<N> {
 
                         // assign die number since its not covered
                         for entry in self.entries.iter().rev() {
-                            if entry.num.is_some() {
-                                new_entry.num = Some(entry.num.unwrap() + 1);
+                            if let Some(num) = entry.num {
+                                new_entry.num = Some(num + 1);
                                 break;
                             }
                         }
@@ -146,6 +188,96 @@ impl<const N: usize> ConesnsusTable<N> {
         }
     }
 
+    pub fn entries(&self) -> &[ConsensusTableEntry<N>] {
+        &self.entries
+    }
+
+    /// Returns the length of the longest chain of merges deriving a die of the table. <br>
+    /// Input dice have a depth of 0, merged dice one more than the deeper of their creators
+    pub fn derivation_depth(&self) -> usize {
+        let mut depths: Vec<usize> = Vec::with_capacity(self.entries.len());
+        for entry in &self.entries {
+            let depth = entry.creators.map_or(0, |creators| {
+                creators
+                    .iter()
+                    .filter_map(|&creator| self.entries.iter().position(|e| e.num == Some(creator)))
+                    .map(|i| depths[i] + 1)
+                    .max()
+                    .unwrap_or(0)
+            });
+            depths.push(depth);
+        }
+
+        depths.into_iter().max().unwrap_or(0)
+    }
+
+    /// Returns the graph of how each die of the table was derived and what it is covered by
+    pub fn derivation_graph(&self) -> DerivationGraph<'_, N> {
+        DerivationGraph::new(self)
+    }
+
+    /// Returns the dice of all entries that are not covered by any other entry. <br>
+    /// After solving, these are the prime implicants of the function,
+    /// unless the table was built with `new` from input dice covering each other
+    pub fn prime_implicants(&self) -> Vec<Die<N>> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.covered.is_none())
+            .map(|entry| entry.die)
+            .collect()
+    }
+
+    /// Checks if the prime implicants describe the same function as the input dice. <br>
+    /// Terms covered by the dont care dice may differ. The check works on dice, so it stays fast for many variables
+    pub fn is_equivalent_to_input(&self) -> bool {
+        let inputs = self.entries.iter().filter(|entry| entry.creators.is_none());
+        let dice = Cover::new(
+            inputs
+                .clone()
+                .filter(|entry| !entry.dont_care)
+                .map(|entry| entry.die)
+                .collect(),
+        );
+        let dont_care = Cover::new(
+            inputs
+                .filter(|entry| entry.dont_care)
+                .map(|entry| entry.die)
+                .collect(),
+        );
+        let primes = Cover::new(self.prime_implicants());
+
+        primes.union(&dont_care).cover_contains(&dice)
+            && dice.union(&dont_care).cover_contains(&primes)
+    }
+
+    /// Makes the passed cover free of static-1 hazards by adding consensus terms. <br>
+    /// For every hazard the first prime implicant of the solved table spanning it is added,
+    /// so the table must be solved for the same function the cover implements
+    pub fn hazard_free_cover(&self, cover: &[Die<N>]) -> Vec<Die<N>> {
+        let primes = self.prime_implicants();
+        let mut hazard_free = cover.to_vec();
+
+        for hazard in StaticHazard::find(cover) {
+            // an earlier added consensus term might already span this hazard
+            if hazard_free
+                .iter()
+                .any(|product| product.covers(hazard.transition))
+            {
+                continue;
+            }
+
+            // fall back to the hazard itself if the table doesn't know a prime for it
+            let term = primes
+                .iter()
+                .find(|prime| prime.covers(hazard.transition))
+                .copied()
+                .unwrap_or(hazard.transition);
+            hazard_free.push(term);
+        }
+
+        hazard_free
+    }
+
     fn entry_to_string(
         &self,
         entry: &ConsensusTableEntry<N>,
@@ -188,6 +320,22 @@ impl<const N: usize> From<Vec<ConsensusTableEntry<N>>> for ConesnsusTable<N> {
     }
 }
 
+impl<const N: usize> From<&ConesnsusTable<N>> for SecondQuineTable<N> {
+    /// Creates the quine table for the prime implicants of the table,
+    /// leaving out the terms of the table's dont care dice
+    fn from(table: &ConesnsusTable<N>) -> Self {
+        SecondQuineTable::new(
+            table.prime_implicants(),
+            table
+                .entries
+                .iter()
+                .filter(|entry| entry.creators.is_none() && entry.dont_care)
+                .map(|entry| entry.die)
+                .collect(),
+        )
+    }
+}
+
 impl<const N: usize> Display for ConesnsusTable<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut biggest_num = NUM_TITLE.len();
@@ -216,14 +364,11 @@ impl<const N: usize> Display for ConesnsusTable<N> {
         };
 
         for entry in &self.entries {
-            match entry.num {
-                Some(val) => {
-                    let num_len = val.to_string().len();
-                    if num_len > biggest_num {
-                        biggest_num = num_len;
-                    }
+            if let Some(val) = entry.num {
+                let num_len = val.to_string().len();
+                if num_len > biggest_num {
+                    biggest_num = num_len;
                 }
-                _ => {}
             }
 
             if entry.creators.is_some() {
@@ -243,17 +388,13 @@ impl<const N: usize> Display for ConesnsusTable<N> {
             }
 
             // only look at not optional dice, since optional dice will be marked with 'X'
-            match entry.covered {
-                Some(val) => match entry.num {
-                    Some(num) if num != val => {
-                        let num_len = val.to_string().len();
-                        if num_len > biggest_covered {
-                            biggest_covered = num_len;
-                        }
+            if let (Some(val), Some(num)) = (entry.covered, entry.num) {
+                if num != val {
+                    let num_len = val.to_string().len();
+                    if num_len > biggest_covered {
+                        biggest_covered = num_len;
                     }
-                    _ => {}
-                },
-                _ => {}
+                }
             }
         }
 
@@ -288,3 +429,57 @@ impl<const N: usize> Display for ConesnsusTable<N> {
         Ok(())
     }
 }
+
+/// The JSON schema of a consensus table, the same one the table is serialized with
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ConesnsusTableData<const N: usize> {
+    entries: Vec<ConsensusTableEntry<N>>,
+}
+
+/// Every entry must be numbered, in ascending order,
+/// and may only be covered by existing entries and created by entries above them
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for ConesnsusTable<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let data = ConesnsusTableData::<N>::deserialize(deserializer)?;
+
+        if let Some(i) = data.entries.iter().position(|entry| entry.num.is_none()) {
+            return Err(D::Error::custom(format!("entry {i} has no number")));
+        }
+
+        let nums: Vec<usize> = data.entries.iter().filter_map(|entry| entry.num).collect();
+        if nums.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(D::Error::custom(
+                "entry numbers must be ascending and unique",
+            ));
+        }
+
+        for (i, entry) in data.entries.iter().enumerate() {
+            if let Some(covered) = entry.covered {
+                if !nums.contains(&covered) {
+                    return Err(D::Error::custom(format!(
+                        "entry {i} is covered by entry {covered}, which doesn't exist"
+                    )));
+                }
+            }
+
+            for creator in entry.creators.iter().flatten() {
+                let created_before = data.entries[..i]
+                    .iter()
+                    .any(|other| other.num == Some(*creator));
+                if !created_before {
+                    return Err(D::Error::custom(format!(
+                        "entry {i} is created by entry {creator}, which doesn't exist above it"
+                    )));
+                }
+            }
+        }
+
+        Ok(ConesnsusTable {
+            entries: data.entries,
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use crate::Die;
 
 /// Represents an entry in the table used in the consensus method
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsensusTableEntry<const N: usize> {
     pub num: Option<usize>,
     pub creators: Option<[usize; 2]>,
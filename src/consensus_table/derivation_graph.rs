@@ -0,0 +1,130 @@
+use std::fmt::Write;
+
+use super::{ConesnsusTable, ConsensusTableEntry};
+
+/// The derivation of the dice of a consensus table as a directed acyclic graph. <br>
+/// Every numbered entry is a node, with solid edges from the two dice it was merged from
+/// and a dashed edge to the die it is covered by. <br>
+/// Prime implicants are drawn with a double border, dont care dice with a dashed border
+pub struct DerivationGraph<'a, const N: usize> {
+    table: &'a ConesnsusTable<N>,
+}
+
+impl<'a, const N: usize> DerivationGraph<'a, N> {
+    pub(super) fn new(table: &'a ConesnsusTable<N>) -> Self {
+        DerivationGraph { table }
+    }
+
+    /// Renders the graph in the Graphviz DOT language
+    ///
+    /// Example: <br>
+    /// `dot -Tsvg graph.dot > graph.svg`
+    pub fn dot(&self) -> String {
+        let mut ret = String::from("digraph derivation {\n");
+        ret.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+
+        for (num, entry) in self.nodes() {
+            let style = match NodeKind::of(entry) {
+                NodeKind::Prime => ", peripheries=2",
+                NodeKind::DontCare => ", style=dashed",
+                NodeKind::Covered => "",
+            };
+            let _ = writeln!(ret, "    n{num} [label=\"{num}: {}\"{style}];", entry.die);
+        }
+
+        for (num, entry) in self.nodes() {
+            if let Some(creators) = entry.creators {
+                for creator in creators {
+                    let _ = writeln!(ret, "    n{creator} -> n{num};");
+                }
+            }
+        }
+
+        for (num, covered) in self.coverage() {
+            let _ = writeln!(
+                ret,
+                "    n{num} -> n{covered} [style=dashed, label=\"covered by\"];"
+            );
+        }
+
+        ret.push_str("}\n");
+        ret
+    }
+
+    /// Renders the graph as a Mermaid flowchart
+    pub fn mermaid(&self) -> String {
+        let mut ret = String::from("flowchart TD\n");
+
+        for (num, entry) in self.nodes() {
+            let _ = writeln!(ret, "    n{num}[\"{num}: {}\"]", entry.die);
+        }
+
+        for (num, entry) in self.nodes() {
+            if let Some(creators) = entry.creators {
+                for creator in creators {
+                    let _ = writeln!(ret, "    n{creator} --> n{num}");
+                }
+            }
+        }
+
+        for (num, covered) in self.coverage() {
+            let _ = writeln!(ret, "    n{num} -. covered by .-> n{covered}");
+        }
+
+        for (kind, class, style) in [
+            (NodeKind::Prime, "prime", "stroke-width:3px"),
+            (NodeKind::DontCare, "dontCare", "stroke-dasharray:5 5"),
+        ] {
+            let nodes: Vec<String> = self
+                .nodes()
+                .filter(|(_, entry)| NodeKind::of(entry) == kind)
+                .map(|(num, _)| format!("n{num}"))
+                .collect();
+
+            if !nodes.is_empty() {
+                let _ = writeln!(ret, "    classDef {class} {style}");
+                let _ = writeln!(ret, "    class {} {class}", nodes.join(","));
+            }
+        }
+
+        ret
+    }
+
+    /// Returns all numbered entries together with their number
+    fn nodes(&self) -> impl Iterator<Item = (usize, &'a ConsensusTableEntry<N>)> {
+        self.table
+            .entries()
+            .iter()
+            .filter_map(|entry| entry.num.map(|num| (num, entry)))
+    }
+
+    /// Returns the number of each covered entry together with the number of the entry covering it. <br>
+    /// Dont care dice covering themselves are left out
+    fn coverage(&self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.table.entries().iter().filter_map(|entry| match entry {
+            ConsensusTableEntry {
+                num: Some(num),
+                covered: Some(covered),
+                ..
+            } if num != covered => Some((*num, *covered)),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    Prime,
+    DontCare,
+    Covered,
+}
+
+impl NodeKind {
+    fn of<const N: usize>(entry: &ConsensusTableEntry<N>) -> Self {
+        match (entry.dont_care, entry.covered) {
+            (true, _) => NodeKind::DontCare,
+            (false, None) => NodeKind::Prime,
+            (false, Some(_)) => NodeKind::Covered,
+        }
+    }
+}
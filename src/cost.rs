@@ -0,0 +1,2 @@
+mod cost_function;
+pub use cost_function::{CostFunction, GateInputCount, LiteralCount, LiteralWeights, ProductCount};
@@ -0,0 +1,74 @@
+use crate::{Die, DieSide};
+
+/// Defines what makes a cover minimal. <br>
+/// The cost of a cover is the sum of the costs of its dice, unless `cover_cost` charges a single product less
+pub trait CostFunction<const N: usize> {
+    /// Returns the cost of a single product of the cover
+    fn die_cost(&self, die: &Die<N>) -> usize;
+
+    /// Returns the cost of the whole cover. <br>
+    /// Overrides may only charge a cover of a single product less than the sum of its dice
+    fn cover_cost(&self, dice: &[Die<N>]) -> usize {
+        dice.iter().map(|die| self.die_cost(die)).sum()
+    }
+}
+
+/// Every product costs 1, so the cover with the fewest products is minimal
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProductCount;
+
+impl<const N: usize> CostFunction<N> for ProductCount {
+    fn die_cost(&self, _die: &Die<N>) -> usize {
+        1
+    }
+}
+
+/// Every literal costs 1, so the cover with the fewest literals in total is minimal
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LiteralCount;
+
+impl<const N: usize> CostFunction<N> for LiteralCount {
+    fn die_cost(&self, die: &Die<N>) -> usize {
+        die.literal_count()
+    }
+}
+
+/// Counts the inputs of the AND gates and the final OR gate of the two-level circuit. <br>
+/// A product of a single literal doesn't need an AND gate, but still feeds the OR gate.
+/// A cover of a single product doesn't need the OR gate at all
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GateInputCount;
+
+impl<const N: usize> CostFunction<N> for GateInputCount {
+    fn die_cost(&self, die: &Die<N>) -> usize {
+        match die.literal_count() {
+            0 | 1 => 1,
+            literals => literals + 1,
+        }
+    }
+
+    fn cover_cost(&self, dice: &[Die<N>]) -> usize {
+        match dice {
+            // the single product drives the output directly, without the OR gate input
+            [die] => self.die_cost(die) - 1,
+            dice => dice.iter().map(|die| self.die_cost(die)).sum(),
+        }
+    }
+}
+
+/// Every literal costs the weight of its variable, e.g. to model inputs that are expensive to route
+#[derive(Clone, Copy, Debug)]
+pub struct LiteralWeights<const N: usize> {
+    pub weights: [usize; N],
+}
+
+impl<const N: usize> CostFunction<N> for LiteralWeights<N> {
+    fn die_cost(&self, die: &Die<N>) -> usize {
+        die.content
+            .iter()
+            .zip(self.weights)
+            .filter(|(&side, _)| side != DieSide::DontCare)
+            .map(|(_, weight)| weight)
+            .sum()
+    }
+}
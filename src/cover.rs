@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod cover;
+pub use cover::Cover;
+mod compiled_cover;
+pub use compiled_cover::CompiledCover;
+mod tautology;
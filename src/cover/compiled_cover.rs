@@ -0,0 +1,46 @@
+use super::Cover;
+
+/// A cover compiled into one mask and value per die, for evaluating many assignments quickly. <br>
+/// An assignment holds the first variable in its most significant bit, like a minterm,
+/// and is covered by a die when `assignment & mask == value`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledCover<const N: usize> {
+    products: Vec<(u64, u64)>,
+}
+
+impl<const N: usize> CompiledCover<N> {
+    pub fn new(cover: &Cover<N>) -> Self {
+        if N > 64 {
+            panic!(
+                "Covers can only be compiled for at most 64 variables, but have {}",
+                N
+            );
+        }
+
+        CompiledCover {
+            products: cover
+                .dice
+                .iter()
+                .map(|die| {
+                    let (mask, value) = die.mask();
+                    (mask as u64, value as u64)
+                })
+                .collect(),
+        }
+    }
+
+    /// Checks if the cover is true for the assignment
+    pub fn eval(&self, assignment: u64) -> bool {
+        self.products
+            .iter()
+            .any(|&(mask, value)| assignment & mask == value)
+    }
+
+    /// Evaluates the cover for every assignment
+    pub fn eval_many(&self, assignments: &[u64]) -> Vec<bool> {
+        assignments
+            .iter()
+            .map(|&assignment| self.eval(assignment))
+            .collect()
+    }
+}
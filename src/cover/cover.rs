@@ -0,0 +1,226 @@
+use std::fmt::Display;
+
+use crate::{Die, DieSide};
+
+use super::CompiledCover;
+
+/// Represents a boolean function as a set of dice, a sum of products
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cover<const N: usize> {
+    pub dice: Vec<Die<N>>,
+}
+
+impl<const N: usize> Cover<N> {
+    /// Creates a cover from the passed dice, dropping every die that is already covered by another one
+    pub fn new(dice: Vec<Die<N>>) -> Self {
+        let mut cover = Cover { dice };
+        cover.absorb();
+        cover
+    }
+
+    /// The cover of the constant 0 function
+    pub fn empty() -> Self {
+        Cover { dice: Vec::new() }
+    }
+
+    /// The cover of the constant 1 function
+    pub fn universe() -> Self {
+        Cover {
+            dice: vec![Die {
+                content: [DieSide::DontCare; N],
+            }],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dice.is_empty()
+    }
+
+    /// Compiles the dice into masks, for evaluating many assignments quickly
+    pub fn compile(&self) -> CompiledCover<N> {
+        CompiledCover::new(self)
+    }
+
+    /// Returns the union of both covers, the OR of both functions
+    pub fn union(&self, other: &Cover<N>) -> Cover<N> {
+        let mut dice = self.dice.clone();
+        dice.extend_from_slice(&other.dice);
+        Cover::new(dice)
+    }
+
+    /// Returns the intersection of both covers, the AND of both functions. <br>
+    /// Every die of this cover gets intersected with every die of the other one
+    pub fn intersection(&self, other: &Cover<N>) -> Cover<N> {
+        let mut dice = Vec::new();
+        for a in &self.dice {
+            for &b in &other.dice {
+                if let Some(die) = a.intersect(b) {
+                    dice.push(die);
+                }
+            }
+        }
+
+        Cover::new(dice)
+    }
+
+    /// Returns the complement of the cover, the NOT of the function. <br>
+    /// The complement is computed with the unate recursive paradigm,
+    /// splitting on the most binate variable until a cover is trivial to complement
+    pub fn complement(&self) -> Cover<N> {
+        if self.dice.is_empty() {
+            return Cover::universe();
+        }
+
+        if self
+            .dice
+            .iter()
+            .any(|die| die.content == [DieSide::DontCare; N])
+        {
+            return Cover::empty();
+        }
+
+        // the complement of a single die follows directly from De Morgan's law
+        if self.dice.len() == 1 {
+            let mut dice = Vec::new();
+            for (i, side) in self.dice[0].content.iter().enumerate() {
+                let mut die = Die {
+                    content: [DieSide::DontCare; N],
+                };
+                die.content[i] = match side {
+                    DieSide::Zero => DieSide::One,
+                    DieSide::One => DieSide::Zero,
+                    DieSide::DontCare => continue,
+                };
+                dice.push(die);
+            }
+            return Cover { dice };
+        }
+
+        let variable = self.splitting_variable();
+        let zero = self.cofactor(variable, false).complement();
+        let one = self.cofactor(variable, true).complement();
+
+        // dice in both complements don't depend on the variable
+        let mut dice = Vec::new();
+        for die in &zero.dice {
+            if one.dice.contains(die) {
+                dice.push(*die);
+            } else {
+                let mut die = *die;
+                die.content[variable] = DieSide::Zero;
+                dice.push(die);
+            }
+        }
+        for die in &one.dice {
+            if !zero.dice.contains(die) {
+                let mut die = *die;
+                die.content[variable] = DieSide::One;
+                dice.push(die);
+            }
+        }
+
+        Cover::new(dice)
+    }
+
+    /// Returns the sharp product of both covers, all terms this cover covers, that the other one doesn't
+    pub fn sharp(&self, other: &Cover<N>) -> Cover<N> {
+        self.intersection(&other.complement())
+    }
+
+    /// Returns the exclusive or of both covers
+    pub fn xor(&self, other: &Cover<N>) -> Cover<N> {
+        self.sharp(other).union(&other.sharp(self))
+    }
+
+    /// Returns the Shannon cofactor of the cover with respect to the variable. <br>
+    /// Dice opposing the value are dropped, the variable becomes a DontCare in all other dice
+    ///
+    /// Example: <br>
+    /// `(1 0 -)`, `(0 - 1)`, `(- 1 1)` cofactored with variable 0 set to 1 => `(- 0 -)`, `(- 1 1)`
+    pub fn cofactor(&self, variable: usize, value: bool) -> Cover<N> {
+        let opposed = if value { DieSide::Zero } else { DieSide::One };
+
+        let dice = self
+            .dice
+            .iter()
+            .filter(|die| die.content[variable] != opposed)
+            .map(|&die| {
+                let mut die = die;
+                die.content[variable] = DieSide::DontCare;
+                die
+            })
+            .collect();
+
+        Cover::new(dice)
+    }
+
+    /// Picks the variable to split on, preferring variables that appear in both polarities
+    fn splitting_variable(&self) -> usize {
+        let mut best = (0, 0, 0);
+        for i in 0..N {
+            let zeros = self
+                .dice
+                .iter()
+                .filter(|die| die.content[i] == DieSide::Zero)
+                .count();
+            let ones = self
+                .dice
+                .iter()
+                .filter(|die| die.content[i] == DieSide::One)
+                .count();
+
+            let binate = zeros.min(ones);
+            if (binate, zeros + ones) > (best.1, best.2) {
+                best = (i, binate, zeros + ones);
+            }
+        }
+
+        best.0
+    }
+
+    /// Removes all dice that are covered by another die of the cover. <br>
+    /// Of two equal dice the later one is removed
+    fn absorb(&mut self) {
+        let mut i = 0;
+        while i < self.dice.len() {
+            let die = self.dice[i];
+            let absorbed = self
+                .dice
+                .iter()
+                .enumerate()
+                .any(|(j, other)| j != i && other.covers(die) && (*other != die || j < i));
+
+            if absorbed {
+                self.dice.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+impl<const N: usize> From<Vec<Die<N>>> for Cover<N> {
+    fn from(dice: Vec<Die<N>>) -> Self {
+        Cover::new(dice)
+    }
+}
+
+impl<const N: usize> Display for Cover<N> {
+    /// Returns the cover as sum of its dice, e.g. `(1 - 0) + (0 1 -)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.dice.is_empty() {
+            return write!(f, "0");
+        }
+
+        write!(
+            f,
+            "{}",
+            self.dice
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(" + ")
+        )
+    }
+}
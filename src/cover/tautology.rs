@@ -0,0 +1,92 @@
+use crate::{Die, DieSide};
+
+use super::Cover;
+
+impl<const N: usize> Cover<N> {
+    /// Checks if the cover is the constant 1 function. <br>
+    /// The check recursively splits on binate variables and never enumerates minterms.
+    /// A unate cover is a tautology exactly when it contains the die of only DontCares
+    pub fn is_tautology(&self) -> bool {
+        if self
+            .dice
+            .iter()
+            .any(|die| die.content == [DieSide::DontCare; N])
+        {
+            return true;
+        }
+
+        let Some(variable) = self.binate_variable() else {
+            return false;
+        };
+
+        self.cofactor(variable, false).is_tautology()
+            && self.cofactor(variable, true).is_tautology()
+    }
+
+    /// Checks if every term covered by the other cover is also covered by this one
+    pub fn cover_contains(&self, other: &Cover<N>) -> bool {
+        other
+            .dice
+            .iter()
+            .all(|&die| self.die_cofactor(die).is_tautology())
+    }
+
+    /// Checks if both covers describe the same function, even if they consist of different dice
+    pub fn covers_equal(&self, other: &Cover<N>) -> bool {
+        self.cover_contains(other) && other.cover_contains(self)
+    }
+
+    /// Returns the cofactor of the cover with respect to a die. <br>
+    /// Only dice intersecting the die are kept, with all positions the die sets replaced by DontCares
+    fn die_cofactor(&self, die: Die<N>) -> Cover<N> {
+        Cover::dice_cofactor(&self.dice, die)
+    }
+
+    /// Returns the cofactor of the union of the dice with respect to a die, like `die_cofactor`. <br>
+    /// Only the dice intersecting the die end up in a cover, so big sets of dice don't have to be absorbed first
+    pub(crate) fn dice_cofactor<'a>(
+        dice: impl IntoIterator<Item = &'a Die<N>>,
+        die: Die<N>,
+    ) -> Cover<N> {
+        let dice = dice
+            .into_iter()
+            .filter(|other| other.intersect(die).is_some())
+            .map(|&other| {
+                let mut other = other;
+                for i in 0..N {
+                    if die.content[i] != DieSide::DontCare {
+                        other.content[i] = DieSide::DontCare;
+                    }
+                }
+                other
+            })
+            .collect();
+
+        Cover::new(dice)
+    }
+
+    /// Returns the variable appearing most often in both polarities, or none if the cover is unate
+    fn binate_variable(&self) -> Option<usize> {
+        let mut best = None;
+        let mut best_count = 0;
+        for i in 0..N {
+            let zeros = self
+                .dice
+                .iter()
+                .filter(|die| die.content[i] == DieSide::Zero)
+                .count();
+            let ones = self
+                .dice
+                .iter()
+                .filter(|die| die.content[i] == DieSide::One)
+                .count();
+
+            if zeros > 0 && ones > 0 && zeros + ones > best_count {
+                best = Some(i);
+                best_count = zeros + ones;
+            }
+        }
+
+        best
+    }
+}
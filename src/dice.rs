@@ -1,2 +1,42 @@
-mod die_side; pub use die_side::DieSide;
-mod die; pub use die::Die;
\ No newline at end of file
+mod die_side;
+pub use die_side::DieSide;
+mod die;
+pub use die::Die;
+mod minterms;
+pub use minterms::Minterms;
+mod numbering;
+pub use numbering::Numbering;
+
+/// The index of a minterm, the first variable of a die being the most significant bit. <br>
+/// Dice with up to 128 variables can be indexed
+pub type Minterm = u128;
+
+/// Creates a die from a binary string literal at compile time, inferring N from its length. <br>
+/// Characters other than 0s, 1s and - fail to compile
+///
+/// Example: <br>
+/// `die!("1-0")` is `(1 - 0)`
+///
+/// ```
+/// # use consensus_solver::{die, Die};
+/// let die: Die<3> = die!("1-0");
+/// assert_eq!(die, Die::new("1-0"));
+/// ```
+///
+/// Invalid characters don't compile:
+/// ```compile_fail
+/// # use consensus_solver::die;
+/// let die = die!("1x0");
+/// ```
+///
+/// Neither does a literal of another length than the annotated die:
+/// ```compile_fail
+/// # use consensus_solver::{die, Die};
+/// let die: Die<3> = die!("10");
+/// ```
+#[macro_export]
+macro_rules! die {
+    ($value:literal) => {
+        const { $crate::Die::<{ $value.len() }>::from_binary_string($value) }
+    };
+}
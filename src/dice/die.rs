@@ -1,30 +1,49 @@
 use std::fmt::Display;
 
-use super::DieSide;
+use super::{DieSide, Minterm, Minterms, Numbering};
 
 /// Represents a boolean algebraic die
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Die<const N: usize> {
     pub content: [DieSide; N],
 }
 
 impl<const N: usize> Die<N> {
+    /// Parses the die from a binary string like `"10-"`, see `from_binary_string`
     pub fn new<S: AsRef<str>>(value: S) -> Self {
-        if value.as_ref().len() != N {
-            panic!("Value must be of length {}", N);
+        Die::from_binary_string(value.as_ref())
+    }
+
+    /// Parses the die from a binary string like `"10-"`, usable in constants. <br>
+    /// Panics if the string isn't of length N or contains anything but 0s, 1s and -,
+    /// which is a compile error when evaluated at compile time, see [`die!`](crate::die)
+    pub const fn from_binary_string(value: &str) -> Self {
+        match Die::parse_binary_string(value) {
+            Ok(die) => die,
+            Err(reason) => panic!("{}", reason),
+        }
+    }
+
+    /// Parses the die like `from_binary_string`, returning the reason instead of panicking
+    const fn parse_binary_string(value: &str) -> Result<Self, &'static str> {
+        let bytes = value.as_bytes();
+        if bytes.len() != N {
+            return Err("Value must be of the length of the die");
         }
 
         let mut content = [DieSide::DontCare; N];
-        for (i, c) in value.as_ref().chars().enumerate() {
-            match c {
-                '0' => content[i] = DieSide::Zero,
-                '1' => content[i] = DieSide::One,
-                '-' => content[i] = DieSide::DontCare,
-                _ => panic!("Invalid value, must only be 0s, 1s and -"),
-            }
+        let mut i = 0;
+        while i < N {
+            content[i] = match bytes[i] {
+                b'0' => DieSide::Zero,
+                b'1' => DieSide::One,
+                b'-' => DieSide::DontCare,
+                _ => return Err("Invalid value, must only be 0s, 1s and -"),
+            };
+            i += 1;
         }
 
-        Die { content }
+        Ok(Die { content })
     }
 
     /// Tries to merge two dice. <br>
@@ -36,6 +55,13 @@ impl<const N: usize> Die<N> {
     /// (0 0 1 -) <br>
     /// (- 0 1 0) <=
     pub fn merge(&self, other: Die<N>) -> Option<Die<N>> {
+        self.consensus(other)
+    }
+
+    /// Builds the consensus of two dice. <br>
+    /// The consensus exists when the dice are opposed in exactly 1 variable.
+    /// That variable becomes a DontCare and all other positions are intersected
+    pub fn consensus(&self, other: Die<N>) -> Option<Die<N>> {
         // check how many different die sides the dice have and ignore DontCares
         let mut diffs = Vec::new();
         for i in 0..N {
@@ -52,23 +78,34 @@ impl<const N: usize> Die<N> {
             return None;
         }
 
-        let mut die = Die {
-            content: [DieSide::Zero; N],
-        };
+        let mut a = *self;
+        let mut b = other;
+        a.content[diffs[0]] = DieSide::DontCare;
+        b.content[diffs[0]] = DieSide::DontCare;
+
+        // can't fail, since the only opposed variable was removed
+        a.intersect(b)
+    }
+
+    /// Intersects two dice. <br>
+    /// Returns the die covering exactly the terms both dice cover, or none if the dice are disjoint
+    ///
+    /// Example: <br>
+    /// (1 - -) <br>
+    /// (- 0 1) <br>
+    /// (1 0 1) <=
+    pub fn intersect(&self, other: Die<N>) -> Option<Die<N>> {
+        let mut die = *self;
         for i in 0..N {
-            // if one die has a DontCare, copy the other die's value
-            if self.content[i] == DieSide::DontCare {
-                die.content[i] = other.content[i];
-            } else if other.content[i] == DieSide::DontCare {
-                die.content[i] = self.content[i];
-            } else {
-                // else just use either value, since they must be equal besides one difference
-                die.content[i] = self.content[i];
+            match (self.content[i], other.content[i]) {
+                // if one die has a DontCare, copy the other die's value
+                (DieSide::DontCare, side) => die.content[i] = side,
+                (_, DieSide::DontCare) => {}
+                (a, b) if a != b => return None,
+                _ => {}
             }
         }
 
-        // Set the one difference to DontCare
-        die.content[diffs[0]] = DieSide::DontCare;
         Some(die)
     }
 
@@ -89,6 +126,59 @@ impl<const N: usize> Die<N> {
         true
     }
 
+    /// Returns the number of variables the die doesn't have a DontCare for
+    pub fn literal_count(&self) -> usize {
+        self.content
+            .iter()
+            .filter(|&&side| side != DieSide::DontCare)
+            .count()
+    }
+
+    /// Returns the number of minterms the die covers, which is 2 to the power of its DontCares. <br>
+    /// Panics if the die has 128 or more DontCares, since the count doesn't fit into a `Minterm`
+    pub fn minterm_count(&self) -> Minterm {
+        let dont_cares = N - self.literal_count();
+        (1 as Minterm)
+            .checked_shl(dont_cares as u32)
+            .unwrap_or_else(|| {
+                panic!(
+                    "A die with {} DontCares covers too many minterms to count",
+                    dont_cares
+                )
+            })
+    }
+
+    /// Iterates the minterms the die covers in ascending order. <br>
+    /// The first variable is the most significant bit
+    ///
+    /// Example: <br>
+    /// `(1 - 0 -)` covers 8, 9, 12 and 13
+    pub fn minterms(&self) -> Minterms {
+        Numbering::msb_first().minterms(self)
+    }
+
+    /// Returns the mask of the variables the die fixes, and the value they are fixed to,
+    /// the first variable being the most significant bit
+    ///
+    /// Example: <br>
+    /// `(1 0 -)` has the mask `0b110` and the value `0b100`
+    pub fn mask(&self) -> (Minterm, Minterm) {
+        Numbering::msb_first().mask(self)
+    }
+
+    /// Checks if the die covers the minterm, the first variable being the most significant bit
+    pub fn covers_minterm(&self, minterm: Minterm) -> bool {
+        Numbering::msb_first().covers(self, minterm)
+    }
+
+    /// Parses the minterm into the die covering only that minterm, the first variable being the most significant bit
+    ///
+    /// Example: <br>
+    /// `6` is `(1 1 0)` with 3 variables
+    pub fn from_minterm(minterm: Minterm) -> Self {
+        Numbering::msb_first().die(minterm)
+    }
+
     pub fn as_binary_string(&self) -> String {
         self.content.iter().map(ToString::to_string).collect()
     }
@@ -108,3 +198,22 @@ impl<const N: usize> Display for Die<N> {
         )
     }
 }
+
+/// Dice are stored as their binary string, e.g. `"10-"`
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Die<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_binary_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Die<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let value = String::deserialize(deserializer)?;
+        Die::parse_binary_string(&value)
+            .map_err(|reason| D::Error::custom(format!("die \"{value}\" is invalid: {reason}")))
+    }
+}
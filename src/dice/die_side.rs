@@ -1,10 +1,14 @@
 use std::fmt::Display;
 
 /// The values a die's side can have
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DieSide {
+    #[cfg_attr(feature = "serde", serde(rename = "0"))]
     Zero,
+    #[cfg_attr(feature = "serde", serde(rename = "1"))]
     One,
+    #[cfg_attr(feature = "serde", serde(rename = "-"))]
     DontCare,
 }
 
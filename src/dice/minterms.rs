@@ -0,0 +1,47 @@
+use super::Minterm;
+
+/// Iterates the minterms a die covers in ascending order, without allocating. <br>
+/// The bits of the minterms depend on the `Numbering` that created the iterator
+pub struct Minterms {
+    /// The bits of the variables that are fixed to one
+    ones: Minterm,
+    /// The bits of the variables that are DontCares
+    dont_cares: Minterm,
+    /// The DontCare bits of the next minterm, or none when all minterms were returned
+    next: Option<Minterm>,
+}
+
+impl Minterms {
+    pub(super) fn new(ones: Minterm, dont_cares: Minterm) -> Self {
+        Minterms {
+            ones,
+            dont_cares,
+            next: Some(0),
+        }
+    }
+}
+
+/// Panics if the minterms of dice with N variables don't fit into a `Minterm`
+pub(super) fn check_width<const N: usize>() {
+    if N > Minterm::BITS as usize {
+        panic!(
+            "Minterms of dice with more than {} variables can't be indexed, but the dice have {}",
+            Minterm::BITS,
+            N
+        );
+    }
+}
+
+impl Iterator for Minterms {
+    type Item = Minterm;
+
+    fn next(&mut self) -> Option<Minterm> {
+        let current = self.next?;
+
+        // count up only within the DontCare bits, by letting the carry run through all other bits
+        let next = (current | !self.dont_cares).wrapping_add(1) & self.dont_cares;
+        self.next = (next != 0).then_some(next);
+
+        Some(self.ones | current)
+    }
+}
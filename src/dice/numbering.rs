@@ -0,0 +1,179 @@
+use super::{minterms::check_width, Die, DieSide, Minterm, Minterms};
+
+/// Describes how the variables of a die are numbered when converting between dice and minterms. <br>
+/// Textbooks differ in whether the first variable is the most or the least significant bit,
+/// and some use their own variable order
+///
+/// Example: <br>
+/// `(1 0 0)` is minterm 4 when numbered MSB first, and minterm 1 when numbered LSB first
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Numbering<const N: usize> {
+    /// The bit of the minterm each die position is stored in, 0 being the least significant bit
+    bits: [usize; N],
+}
+
+impl<const N: usize> Numbering<N> {
+    /// The first variable of the die is the most significant bit
+    pub fn msb_first() -> Self {
+        let mut bits = [0; N];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = N - 1 - i;
+        }
+        Numbering { bits }
+    }
+
+    /// The first variable of the die is the least significant bit
+    pub fn lsb_first() -> Self {
+        let mut bits = [0; N];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = i;
+        }
+        Numbering { bits }
+    }
+
+    /// Numbers the variables in the passed order. <br>
+    /// `order` lists the die positions from the most to the least significant bit
+    /// and must contain every position exactly once
+    ///
+    /// Example: <br>
+    /// `[2, 0, 1]` makes the third variable the most significant bit
+    pub fn permutation(order: [usize; N]) -> Self {
+        let mut bits = [N; N];
+        for (k, &position) in order.iter().enumerate() {
+            if position >= N || bits[position] != N {
+                panic!(
+                    "Order must contain every position from 0 to {} exactly once",
+                    N - 1
+                );
+            }
+            bits[position] = N - 1 - k;
+        }
+        Numbering { bits }
+    }
+
+    /// Returns the minterm bit the die position is stored in, 0 being the least significant bit
+    pub fn bit(&self, position: usize) -> usize {
+        self.bits[position]
+    }
+
+    /// Iterates the minterms the die covers in ascending order
+    pub fn minterms(&self, die: &Die<N>) -> Minterms {
+        check_width::<N>();
+
+        let mut ones = 0;
+        let mut dont_cares = 0;
+        for (side, &bit) in die.content.iter().zip(&self.bits) {
+            match side {
+                DieSide::One => ones |= 1 << bit,
+                DieSide::DontCare => dont_cares |= 1 << bit,
+                DieSide::Zero => {}
+            }
+        }
+
+        Minterms::new(ones, dont_cares)
+    }
+
+    /// Returns the minterms any of the dice covers in ascending order
+    pub fn cover_minterms(&self, dice: &[Die<N>]) -> Vec<Minterm> {
+        let mut minterms: Vec<Minterm> = dice.iter().flat_map(|die| self.minterms(die)).collect();
+        minterms.sort();
+        minterms.dedup();
+        minterms
+    }
+
+    /// Returns the minterms the ON dice cover in ascending order, without the ones a dont care die covers
+    pub fn on_minterms(&self, on: &[Die<N>], dont_care: &[Die<N>]) -> Vec<Minterm> {
+        let dont_care = self.cover_minterms(dont_care);
+        self.cover_minterms(on)
+            .into_iter()
+            .filter(|minterm| dont_care.binary_search(minterm).is_err())
+            .collect()
+    }
+
+    /// Returns the mask of the variables the die fixes, and the value they are fixed to. <br>
+    /// A minterm is covered when `minterm & mask == value`
+    pub fn mask(&self, die: &Die<N>) -> (Minterm, Minterm) {
+        check_width::<N>();
+
+        let mut mask = 0;
+        let mut value = 0;
+        for (side, &bit) in die.content.iter().zip(&self.bits) {
+            match side {
+                DieSide::One => {
+                    mask |= 1 << bit;
+                    value |= 1 << bit;
+                }
+                DieSide::Zero => mask |= 1 << bit,
+                DieSide::DontCare => {}
+            }
+        }
+        (mask, value)
+    }
+
+    /// Checks if the die covers the minterm
+    pub fn covers(&self, die: &Die<N>, minterm: Minterm) -> bool {
+        let (mask, value) = self.mask(die);
+        minterm & mask == value
+    }
+
+    /// Parses the minterm into the die covering only that minterm. <br>
+    /// Panics if the minterm has bits set beyond the N variables
+    pub fn die(&self, minterm: Minterm) -> Die<N> {
+        check_width::<N>();
+        if N < Minterm::BITS as usize && minterm >> N != 0 {
+            panic!("Minterm {} has more than {} variables", minterm, N);
+        }
+
+        let mut content = [DieSide::Zero; N];
+        for (side, &bit) in content.iter_mut().zip(&self.bits) {
+            if minterm >> bit & 1 == 1 {
+                *side = DieSide::One;
+            }
+        }
+        Die { content }
+    }
+}
+
+impl<const N: usize> Default for Numbering<N> {
+    fn default() -> Self {
+        Numbering::msb_first()
+    }
+}
+
+/// Numberings are stored as the minterm bit of each die position, e.g. `[2, 1, 0]` for MSB first
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Numbering<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.bits.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Numbering<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let values = Vec::<usize>::deserialize(deserializer)?;
+        if values.len() != N {
+            return Err(D::Error::custom(format!(
+                "numbering must have {N} bits, but has {}",
+                values.len()
+            )));
+        }
+
+        let mut bits = [0; N];
+        let mut used = [false; N];
+        for (bit, value) in bits.iter_mut().zip(values) {
+            if value >= N || used[value] {
+                return Err(D::Error::custom(format!(
+                    "numbering must use every bit from 0 to {} exactly once",
+                    N - 1
+                )));
+            }
+            used[value] = true;
+            *bit = value;
+        }
+
+        Ok(Numbering { bits })
+    }
+}
@@ -0,0 +1,2 @@
+mod difficulty_report;
+pub use difficulty_report::DifficultyReport;
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use crate::{ConesnsusTable, SecondQuineTable};
+
+/// Minimal solutions are only counted up to this number
+const SOLUTION_LIMIT: usize = 1000;
+
+/// Describes how much work solving a function by hand takes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DifficultyReport {
+    /// Number of rows the consensus method added to the table
+    pub generated_rows: usize,
+    /// Length of the longest chain of merges deriving a die of the consensus table,
+    /// see `ConesnsusTable::derivation_depth`
+    pub derivation_depth: usize,
+    pub primes: usize,
+    /// Number of primes that are the only prime covering some term
+    pub essential_primes: usize,
+    /// Rows and columns left after the reduction of the quine table, or none if the reduction solved it
+    pub cyclic_core: Option<(usize, usize)>,
+    /// Number of covers with the fewest primes, counted up to 1000, or none if they weren't counted
+    pub minimal_solutions: Option<usize>,
+}
+
+impl DifficultyReport {
+    /// Measures the function of the solved consensus table, including the number of minimal solutions. <br>
+    /// The table has to be built with `from_cover` if its input dice may cover each other
+    pub fn new<const N: usize>(consensus_table: &ConesnsusTable<N>) -> Self {
+        let mut quine_table = SecondQuineTable::from(consensus_table);
+        quine_table.solve();
+
+        let mut report = DifficultyReport::from_tables(consensus_table, &quine_table);
+        report.count_minimal_solutions(&mut quine_table);
+        report
+    }
+
+    /// Measures the function of the solved consensus table and its quine table, solved for the fewest primes. <br>
+    /// Counting the minimal solutions means enumerating them, so it is left to `count_minimal_solutions`
+    pub fn from_tables<const N: usize>(
+        consensus_table: &ConesnsusTable<N>,
+        quine_table: &SecondQuineTable<N>,
+    ) -> Self {
+        DifficultyReport {
+            generated_rows: consensus_table
+                .entries()
+                .iter()
+                .filter(|entry| entry.creators.is_some())
+                .count(),
+            derivation_depth: consensus_table.derivation_depth(),
+            primes: consensus_table.prime_implicants().len(),
+            essential_primes: quine_table.essential_rows().len(),
+            cyclic_core: quine_table
+                .has_cyclic_core()
+                .then(|| quine_table.cyclic_core_size()),
+            minimal_solutions: None,
+        }
+    }
+
+    /// Counts the minimal solutions with the quine table of the function. <br>
+    /// Searching for all minimal covers skips removing equally expensive rows, so the reduction differs.
+    /// The table is solved for the fewest primes again afterwards
+    pub fn count_minimal_solutions<const N: usize>(
+        &mut self,
+        quine_table: &mut SecondQuineTable<N>,
+    ) {
+        quine_table.solve_all(SOLUTION_LIMIT);
+        self.minimal_solutions = Some(quine_table.solution_count());
+        quine_table.solve();
+    }
+}
+
+impl Display for DifficultyReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Generated rows:    {}", self.generated_rows)?;
+        writeln!(f, "Derivation depth:  {}", self.derivation_depth)?;
+        writeln!(f, "Primes:            {}", self.primes)?;
+        writeln!(f, "Essential primes:  {}", self.essential_primes)?;
+        match self.cyclic_core {
+            Some((rows, columns)) => writeln!(f, "Cyclic core:       {rows} x {columns}")?,
+            None => writeln!(f, "Cyclic core:       none")?,
+        }
+        match self.minimal_solutions {
+            Some(count) => writeln!(f, "Minimal solutions: {count}"),
+            None => writeln!(f, "Minimal solutions: not counted"),
+        }
+    }
+}
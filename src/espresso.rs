@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+mod espresso;
+pub use espresso::Espresso;
+mod espresso_statistics;
+pub use espresso_statistics::EspressoStatistics;
@@ -0,0 +1,227 @@
+use crate::{Cover, Die, DieSide};
+
+use super::EspressoStatistics;
+
+/// A heuristic minimizer following the Espresso loop of EXPAND, IRREDUNDANT and REDUCE. <br>
+/// Unlike the consensus method it never builds all prime implicants,
+/// so it handles functions with many variables and dice, at the cost of only finding a near-minimal cover
+pub struct Espresso<const N: usize> {
+    on: Cover<N>,
+    dont_care: Cover<N>,
+    cover: Cover<N>,
+    statistics: EspressoStatistics,
+}
+
+impl<const N: usize> Espresso<N> {
+    pub fn new(dice: Vec<Die<N>>, dont_care: Vec<Die<N>>) -> Self {
+        let on = Cover::new(dice);
+        let statistics = EspressoStatistics {
+            initial_dice: on.dice.len(),
+            initial_literals: Self::literals(&on),
+            dice: on.dice.len(),
+            literals: Self::literals(&on),
+            ..Default::default()
+        };
+
+        Espresso {
+            cover: on.clone(),
+            on,
+            dont_care: Cover::new(dont_care),
+            statistics,
+        }
+    }
+
+    /// Minimizes the cover, repeating the loop until the cost stops improving
+    pub fn solve(&mut self) {
+        let off = self.on.union(&self.dont_care).complement();
+
+        let mut cover = self.expand(self.on.clone(), &off);
+        cover = self.irredundant(cover);
+        let mut cost = Self::cost(&cover);
+
+        let mut iterations = 0;
+        loop {
+            let mut next = self.reduce(cover.clone());
+            next = self.expand(next, &off);
+            next = self.irredundant(next);
+            iterations += 1;
+
+            let next_cost = Self::cost(&next);
+            if next_cost >= cost {
+                break;
+            }
+            cover = next;
+            cost = next_cost;
+        }
+
+        self.statistics.iterations = iterations;
+        self.statistics.dice = cover.dice.len();
+        self.statistics.literals = Self::literals(&cover);
+        self.statistics.primes = cover
+            .dice
+            .iter()
+            .filter(|&&die| Self::raise(die, &off).is_none())
+            .count();
+        self.cover = cover;
+    }
+
+    /// Returns the minimized cover, or the input cover if the minimizer wasn't solved yet
+    pub fn cover(&self) -> &Cover<N> {
+        &self.cover
+    }
+
+    pub fn statistics(&self) -> &EspressoStatistics {
+        &self.statistics
+    }
+
+    /// Expands every die as far as possible without hitting the off set,
+    /// dropping all dice that get covered by an expanded one
+    fn expand(&self, cover: Cover<N>, off: &Cover<N>) -> Cover<N> {
+        let mut dice = cover.dice;
+        // expand the biggest dice first, since they are the most likely to be primes already
+        dice.sort_by_key(|die| die.literal_count());
+
+        let mut expanded: Vec<Die<N>> = Vec::new();
+        for die in dice {
+            if expanded.iter().any(|other| other.covers(die)) {
+                continue;
+            }
+
+            let mut die = die;
+            while let Some(raised) = Self::best_raise(die, off, &expanded, &self.on) {
+                die = raised;
+            }
+            expanded.retain(|other| !die.covers(*other));
+            expanded.push(die);
+        }
+
+        Cover::new(expanded)
+    }
+
+    /// Removes dice that are covered by the rest of the cover together with the dont cares,
+    /// checking the smallest dice first. <br>
+    /// A die is covered when the cofactor of the rest with respect to the die is a tautology
+    fn irredundant(&self, cover: Cover<N>) -> Cover<N> {
+        let mut dice = cover.dice;
+        dice.sort_by_key(|die| std::cmp::Reverse(die.literal_count()));
+
+        let mut i = 0;
+        while i < dice.len() {
+            let die = dice[i];
+            if Cover::dice_cofactor(Self::rest(&dice, i).chain(&self.dont_care.dice), die)
+                .is_tautology()
+            {
+                dice.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        Cover { dice }
+    }
+
+    /// Shrinks every die to the smallest die still covering the terms no other die covers. <br>
+    /// This gives the following expand a chance to grow the dice in another direction.
+    /// The terms only the die covers are the complement of the cofactor of the rest with respect to the die
+    fn reduce(&self, cover: Cover<N>) -> Cover<N> {
+        let mut dice = cover.dice;
+        dice.sort_by_key(|die| die.literal_count());
+
+        let mut i = 0;
+        while i < dice.len() {
+            let die = dice[i];
+            let unique =
+                Cover::dice_cofactor(Self::rest(&dice, i).chain(&self.dont_care.dice), die)
+                    .complement();
+            match Self::supercube(&unique).and_then(|supercube| supercube.intersect(die)) {
+                Some(reduced) => {
+                    dice[i] = reduced;
+                    i += 1;
+                }
+                None => {
+                    dice.remove(i);
+                }
+            }
+        }
+
+        Cover { dice }
+    }
+
+    /// Iterates all dice besides the one at the index
+    fn rest(dice: &[Die<N>], index: usize) -> impl Iterator<Item = &Die<N>> {
+        dice.iter()
+            .enumerate()
+            .filter(move |&(j, _)| j != index)
+            .map(|(_, die)| die)
+    }
+
+    /// Picks the literal to raise, that lets the die cover the most on set dice and other expanded dice
+    fn best_raise(
+        die: Die<N>,
+        off: &Cover<N>,
+        expanded: &[Die<N>],
+        on: &Cover<N>,
+    ) -> Option<Die<N>> {
+        let mut best: Option<(usize, Die<N>)> = None;
+        for i in 0..N {
+            if die.content[i] == DieSide::DontCare {
+                continue;
+            }
+
+            let mut raised = die;
+            raised.content[i] = DieSide::DontCare;
+            if off
+                .dice
+                .iter()
+                .any(|&other| raised.intersect(other).is_some())
+            {
+                continue;
+            }
+
+            let covered = on
+                .dice
+                .iter()
+                .chain(expanded)
+                .filter(|&&other| raised.covers(other))
+                .count();
+            let better = match best {
+                Some((count, _)) => covered > count,
+                None => true,
+            };
+            if better {
+                best = Some((covered, raised));
+            }
+        }
+
+        best.map(|(_, raised)| raised)
+    }
+
+    /// Returns any literal raise of the die that doesn't hit the off set
+    fn raise(die: Die<N>, off: &Cover<N>) -> Option<Die<N>> {
+        Self::best_raise(die, off, &[], &Cover::empty())
+    }
+
+    /// Returns the smallest die covering all dice of the cover, or none if the cover is empty
+    fn supercube(cover: &Cover<N>) -> Option<Die<N>> {
+        let (first, rest) = cover.dice.split_first()?;
+
+        let mut supercube = *first;
+        for die in rest {
+            for i in 0..N {
+                if supercube.content[i] != die.content[i] {
+                    supercube.content[i] = DieSide::DontCare;
+                }
+            }
+        }
+
+        Some(supercube)
+    }
+
+    fn literals(cover: &Cover<N>) -> usize {
+        cover.dice.iter().map(Die::literal_count).sum()
+    }
+
+    fn cost(cover: &Cover<N>) -> (usize, usize) {
+        (cover.dice.len(), Self::literals(cover))
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+/// Describes how much the heuristic minimizer improved the input cover
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EspressoStatistics {
+    /// Number of reduce, expand and irredundant passes after the initial one
+    pub iterations: usize,
+    pub initial_dice: usize,
+    pub initial_literals: usize,
+    pub dice: usize,
+    pub literals: usize,
+    /// Number of final dice that can't be expanded any further
+    pub primes: usize,
+}
+
+impl Display for EspressoStatistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Iterations: {}", self.iterations)?;
+        writeln!(f, "Dice:       {} -> {}", self.initial_dice, self.dice)?;
+        writeln!(
+            f,
+            "Literals:   {} -> {}",
+            self.initial_literals, self.literals
+        )?;
+        writeln!(f, "Primes:     {} / {}", self.primes, self.dice)
+    }
+}
@@ -0,0 +1,5 @@
+mod exam_problem;
+pub use exam_problem::ExamProblem;
+mod exam_sheet;
+pub use exam_sheet::ExamSheet;
+mod latex;
@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use crate::{Die, Exercise, Minterm, Numbering};
+
+/// A function to minimize on an exam sheet
+///
+/// Problems can be parsed from a text listing the ON and dont care minterms, the dont cares being optional: <br>
+/// `on: 0 1 2 5 6 7` <br>
+/// `dc: 3` <br>
+/// The minterms are always numbered MSB first, the first variable being the most significant bit
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExamProblem<const N: usize> {
+    pub on: Vec<Die<N>>,
+    pub dont_care: Vec<Die<N>>,
+}
+
+impl<const N: usize> ExamProblem<N> {
+    pub fn new(on: Vec<Die<N>>, dont_care: Vec<Die<N>>) -> Self {
+        ExamProblem { on, dont_care }
+    }
+
+    /// Returns the ON minterms numbered MSB first in ascending order, without the ones that are also dont care
+    pub fn on_minterms(&self) -> Vec<Minterm> {
+        Numbering::default().on_minterms(&self.on, &self.dont_care)
+    }
+
+    /// Returns the dont care minterms numbered MSB first in ascending order
+    pub fn dont_care_minterms(&self) -> Vec<Minterm> {
+        Numbering::default().cover_minterms(&self.dont_care)
+    }
+}
+
+impl<const N: usize> From<&Exercise<N>> for ExamProblem<N> {
+    fn from(exercise: &Exercise<N>) -> Self {
+        ExamProblem::new(exercise.on.clone(), exercise.dont_care.clone())
+    }
+}
+
+impl<const N: usize> FromStr for ExamProblem<N> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut on = None;
+        let mut dont_care = Vec::new();
+
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let (key, values) = line
+                .split_once(':')
+                .ok_or_else(|| format!("line \"{line}\" must look like \"on: 0 1 2\""))?;
+
+            let mut dice = Vec::new();
+            for value in values.split(|c: char| c.is_whitespace() || c == ',') {
+                if value.is_empty() {
+                    continue;
+                }
+                let minterm: Minterm = value
+                    .parse()
+                    .map_err(|_| format!("\"{value}\" is not a minterm"))?;
+                if N < Minterm::BITS as usize && minterm >> N != 0 {
+                    return Err(format!("minterm {minterm} has more than {N} variables"));
+                }
+                dice.push(Die::from_minterm(minterm));
+            }
+
+            match key.trim() {
+                "on" => on = Some(dice),
+                "dc" => dont_care = dice,
+                key => return Err(format!("unknown key \"{key}\", expected \"on\" or \"dc\"")),
+            }
+        }
+
+        let on = on.ok_or("the ON minterms are missing")?;
+        Ok(ExamProblem::new(on, dont_care))
+    }
+}
@@ -0,0 +1,197 @@
+use std::fmt::Write;
+
+use crate::{ConesnsusTable, SecondQuineTable};
+
+use super::{latex, ExamProblem};
+
+/// A batch of minimization problems, rendered as a LaTeX problem sheet and a matching answer key. <br>
+/// The problem sheet contains empty consensus and quine table templates of the size the solution needs,
+/// the answer key the filled tables, a minimal DNF and a KV map. <br>
+/// Minterms are always numbered MSB first, other `Numbering`s aren't supported by the sheets
+pub struct ExamSheet<const N: usize> {
+    title: String,
+    problems: Vec<ExamProblem<N>>,
+}
+
+/// A problem together with its solved tables
+struct SolvedProblem<'a, const N: usize> {
+    problem: &'a ExamProblem<N>,
+    consensus_table: ConesnsusTable<N>,
+    quine_table: SecondQuineTable<N>,
+}
+
+impl<const N: usize> ExamSheet<N> {
+    pub fn new<S: Into<String>>(title: S) -> Self {
+        ExamSheet {
+            title: title.into(),
+            problems: Vec::new(),
+        }
+    }
+
+    pub fn add_problem(&mut self, problem: ExamProblem<N>) {
+        self.problems.push(problem);
+    }
+
+    pub fn problems(&self) -> &[ExamProblem<N>] {
+        &self.problems
+    }
+
+    /// Renders the complete LaTeX document with the problems and empty table templates
+    pub fn problem_sheet(&self) -> String {
+        self.document(&self.title, |ret, solved| {
+            Self::consensus_template(ret, solved);
+            Self::quine_template(ret, solved);
+            ret.push_str("\\bigskip\nMinimal DNF: $f = $\n");
+        })
+    }
+
+    /// Renders the complete LaTeX document with the solved tables, a minimal DNF and a KV map of each problem
+    pub fn answer_key(&self) -> String {
+        self.document(&format!("{} -- Answer Key", self.title), |ret, solved| {
+            Self::consensus_solution(ret, solved);
+            Self::quine_solution(ret, solved);
+            let _ = writeln!(
+                ret,
+                "\\bigskip\nMinimal DNF: $f = {}$\n",
+                latex::dnf(&solved.quine_table.solution())
+            );
+            ret.push_str("\\medskip\nKV map:\n\n");
+            ret.push_str(&latex::kv_map::<N>(
+                &solved.problem.on_minterms(),
+                &solved.problem.dont_care_minterms(),
+            ));
+        })
+    }
+
+    fn document<F: Fn(&mut String, &SolvedProblem<N>)>(&self, title: &str, body: F) -> String {
+        let mut ret = String::new();
+        ret.push_str("\\documentclass{article}\n");
+        ret.push_str("\\usepackage[utf8]{inputenc}\n");
+        ret.push_str("\\usepackage{amsmath, amssymb}\n");
+        ret.push_str("\\begin{document}\n");
+        let _ = writeln!(ret, "\\section*{{{}}}", latex::escape(title));
+
+        for (i, problem) in self.problems.iter().enumerate() {
+            let solved = SolvedProblem::new(problem);
+
+            let _ = writeln!(ret, "\n\\subsection*{{Problem {}}}", i + 1);
+            let _ = write!(
+                ret,
+                "Minimize $f({}) = \\sum m({})",
+                latex::variables::<N>(),
+                latex::minterms(&problem.on_minterms())
+            );
+            let dont_care = problem.dont_care_minterms();
+            if !dont_care.is_empty() {
+                let _ = write!(ret, " + \\sum d({})", latex::minterms(&dont_care));
+            }
+            ret.push_str("$.\n\n");
+
+            body(&mut ret, &solved);
+        }
+
+        ret.push_str("\\end{document}\n");
+        ret
+    }
+
+    fn consensus_header(ret: &mut String) {
+        ret.push_str("\\medskip\nConsensus table:\n\n");
+        ret.push_str("\\begin{tabular}{|r|l|c|l|}\n\\hline\n");
+        ret.push_str("Num. & Created by & Die & Covered by \\\\\n\\hline\\hline\n");
+    }
+
+    /// The input dice are given, the rows the consensus method adds are left empty
+    fn consensus_template(ret: &mut String, solved: &SolvedProblem<N>) {
+        Self::consensus_header(ret);
+        for entry in solved.consensus_table.entries() {
+            match (entry.creators, entry.num) {
+                (None, Some(num)) => {
+                    let _ = writeln!(ret, "{num} & & {} & \\\\\n\\hline", latex::die(&entry.die));
+                }
+                _ => ret.push_str(" & & & \\\\\n\\hline\n"),
+            }
+        }
+        ret.push_str("\\end{tabular}\n\n");
+    }
+
+    fn consensus_solution(ret: &mut String, solved: &SolvedProblem<N>) {
+        Self::consensus_header(ret);
+        for entry in solved.consensus_table.entries() {
+            let num = entry.num.map_or(String::new(), |num| num.to_string());
+            let creators = entry
+                .creators
+                .map_or(String::new(), |[a, b]| format!("{a}, {b}"));
+            let covered = match (entry.covered, entry.num) {
+                (Some(covered), Some(num)) if covered == num => "X".to_string(),
+                (Some(covered), _) => format!("$\\subseteq {covered}$"),
+                (None, _) => String::new(),
+            };
+            let _ = writeln!(
+                ret,
+                "{num} & {creators} & {} & {covered} \\\\\n\\hline",
+                latex::die(&entry.die)
+            );
+        }
+        ret.push_str("\\end{tabular}\n\n");
+    }
+
+    fn quine_header(ret: &mut String, solved: &SolvedProblem<N>) {
+        let terms = solved.quine_table.terms();
+        ret.push_str("\\medskip\nQuine table:\n\n");
+        let _ = writeln!(
+            ret,
+            "\\begin{{tabular}}{{{}}}\n\\hline",
+            latex::layout("l", terms.len())
+        );
+        ret.push_str("Die");
+        for term in terms {
+            let _ = write!(ret, " & {term}");
+        }
+        ret.push_str(" \\\\\n\\hline\\hline\n");
+    }
+
+    /// One empty row for each prime implicant
+    fn quine_template(ret: &mut String, solved: &SolvedProblem<N>) {
+        Self::quine_header(ret, solved);
+        let row = " &".repeat(solved.quine_table.terms().len());
+        for _ in solved.quine_table.dice() {
+            let _ = writeln!(ret, "{row} \\\\\n\\hline");
+        }
+        ret.push_str("\\end{tabular}\n\n");
+    }
+
+    fn quine_solution(ret: &mut String, solved: &SolvedProblem<N>) {
+        Self::quine_header(ret, solved);
+        for (row, die) in solved.quine_table.dice().iter().enumerate() {
+            ret.push_str(&latex::die(die));
+            for column in 0..solved.quine_table.terms().len() {
+                let _ = write!(
+                    ret,
+                    " & {}",
+                    latex::symbol(solved.quine_table.symbol(row, column))
+                );
+            }
+            ret.push_str(" \\\\\n\\hline\n");
+        }
+        ret.push_str("\\end{tabular}\n\n");
+        ret.push_str("$\\circledast$ essential, $\\times$ selected or left, ");
+        ret.push_str("$\\mid$ removed column, $-$ removed row\n\n");
+    }
+}
+
+impl<'a, const N: usize> SolvedProblem<'a, N> {
+    fn new(problem: &'a ExamProblem<N>) -> Self {
+        let mut consensus_table =
+            ConesnsusTable::from_cover(problem.on.clone(), problem.dont_care.clone());
+        consensus_table.solve();
+
+        let mut quine_table = SecondQuineTable::from(&consensus_table);
+        quine_table.solve();
+
+        SolvedProblem {
+            problem,
+            consensus_table,
+            quine_table,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{Die, DieSide, Minterm};
+
+/// Escapes the characters LaTeX treats specially, so the text is rendered as is
+pub fn escape(text: &str) -> String {
+    let mut ret = String::new();
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                ret.push('\\');
+                ret.push(c);
+            }
+            '~' => ret.push_str("\\textasciitilde{}"),
+            '^' => ret.push_str("\\textasciicircum{}"),
+            '\\' => ret.push_str("\\textbackslash{}"),
+            c => ret.push(c),
+        }
+    }
+    ret
+}
+
+/// Renders a die in a monospace font, e.g. `\texttt{(1 0 -)}`
+pub fn die<const N: usize>(die: &Die<N>) -> String {
+    format!("\\texttt{{{die}}}")
+}
+
+/// Renders a die as a product of literals, e.g. `x_1 \overline{x_2}`
+pub fn product<const N: usize>(die: &Die<N>) -> String {
+    let literals: Vec<String> = die
+        .content
+        .iter()
+        .enumerate()
+        .filter_map(|(i, side)| match side {
+            DieSide::One => Some(format!("x_{{{}}}", i + 1)),
+            DieSide::Zero => Some(format!("\\overline{{x_{{{}}}}}", i + 1)),
+            DieSide::DontCare => None,
+        })
+        .collect();
+
+    if literals.is_empty() {
+        "1".to_string()
+    } else {
+        literals.join(" ")
+    }
+}
+
+/// Renders dice as a disjunctive normal form, e.g. `x_1 \lor \overline{x_2} x_3`
+pub fn dnf<const N: usize>(dice: &[Die<N>]) -> String {
+    if dice.is_empty() {
+        return "0".to_string();
+    }
+    dice.iter()
+        .map(product)
+        .collect::<Vec<String>>()
+        .join(" \\lor ")
+}
+
+/// Renders the variable list of a function, e.g. `x_1, x_2, x_3`
+pub fn variables<const N: usize>() -> String {
+    (1..=N)
+        .map(|i| format!("x_{{{i}}}"))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Renders a list of minterms, e.g. `0, 1, 5`
+pub fn minterms(minterms: &[Minterm]) -> String {
+    minterms
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Renders a cell symbol of a quine table
+pub fn symbol(symbol: char) -> &'static str {
+    match symbol {
+        'X' => "$\\times$",
+        '⊛' => "$\\circledast$",
+        '│' => "$\\mid$",
+        '─' => "$-$",
+        _ => "",
+    }
+}
+
+/// Renders the tabular column layout of a table with a left column and `columns` centered columns
+pub fn layout(first: &str, columns: usize) -> String {
+    format!("|{first}|{}", "c|".repeat(columns))
+}
+
+/// Renders the KV map of a function with up to 4 variables. <br>
+/// The first half of the variables numbers the rows, the second half the columns, both in gray code
+pub fn kv_map<const N: usize>(on: &[Minterm], dont_care: &[Minterm]) -> String {
+    if N == 0 || N > 4 {
+        return "KV maps are only drawn for functions with 1 to 4 variables.\n".to_string();
+    }
+
+    let row_bits = N / 2;
+    let column_bits = N - row_bits;
+    let gray = |bits: usize| -> Vec<usize> { (0..1 << bits).map(|i| i ^ (i >> 1)).collect() };
+    let label = |value: usize, bits: usize| -> String {
+        (0..bits)
+            .rev()
+            .map(|bit| if value >> bit & 1 == 1 { '1' } else { '0' })
+            .collect()
+    };
+
+    let row_variables: String = (1..=row_bits).map(|i| format!("x_{{{i}}}")).collect();
+    let column_variables: String = (row_bits + 1..=N).map(|i| format!("x_{{{i}}}")).collect();
+
+    let columns = gray(column_bits);
+    let mut ret = format!(
+        "\\begin{{tabular}}{{{}}}\n\\hline\n",
+        layout("c", columns.len())
+    );
+    ret.push_str(&format!("${row_variables} \\setminus {column_variables}$"));
+    for &column in &columns {
+        ret.push_str(&format!(" & {}", label(column, column_bits)));
+    }
+    ret.push_str(" \\\\\n\\hline\n");
+
+    for row in gray(row_bits) {
+        ret.push_str(&label(row, row_bits));
+        for &column in &columns {
+            let minterm = ((row << column_bits) | column) as Minterm;
+            let value = if on.contains(&minterm) {
+                "1"
+            } else if dont_care.contains(&minterm) {
+                "-"
+            } else {
+                "0"
+            };
+            ret.push_str(&format!(" & {value}"));
+        }
+        ret.push_str(" \\\\\n\\hline\n");
+    }
+
+    ret.push_str("\\end{tabular}\n");
+    ret
+}
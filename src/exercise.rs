@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod exercise;
+pub use exercise::Exercise;
+mod exercise_generator;
+pub use exercise_generator::ExerciseGenerator;
+mod split_mix;
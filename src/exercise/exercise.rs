@@ -0,0 +1,15 @@
+use crate::{ConesnsusTable, Die, DifficultyReport, SecondQuineTable};
+
+/// A generated minimization problem together with its solved tables
+pub struct Exercise<const N: usize> {
+    /// The seed of the attempt that produced the exercise. <br>
+    /// Generating with this seed and the same settings yields the exercise again on the first attempt
+    pub seed: u64,
+    /// The ON minterms of the function
+    pub on: Vec<Die<N>>,
+    /// The dont care minterms of the function
+    pub dont_care: Vec<Die<N>>,
+    pub consensus_table: ConesnsusTable<N>,
+    pub quine_table: SecondQuineTable<N>,
+    pub difficulty: DifficultyReport,
+}
@@ -0,0 +1,159 @@
+use crate::{ConesnsusTable, Die, DifficultyReport, Exercise, Minterm, SecondQuineTable};
+
+use super::split_mix::SplitMix;
+
+/// Generates random minimization exercises, that are reproducible from a seed. <br>
+/// Each minterm is ON with the probability `density`, else dont care with the probability `dont_care_ratio`, else OFF.
+/// Functions that miss a target property are rejected and the next function is drawn
+///
+/// Example: <br>
+/// `ExerciseGenerator::<4>::new(7).cyclic_core(true).generate()`
+pub struct ExerciseGenerator<const N: usize> {
+    seed: u64,
+    density: f64,
+    dont_care_ratio: f64,
+    cyclic_core: Option<bool>,
+    essential_primes: Option<usize>,
+    derivation_depth: Option<usize>,
+    minimal_solutions: Option<usize>,
+    max_attempts: usize,
+}
+
+impl<const N: usize> ExerciseGenerator<N> {
+    pub fn new(seed: u64) -> Self {
+        if N > 16 {
+            panic!("Exercises enumerate all minterms, so they can have at most 16 variables");
+        }
+
+        ExerciseGenerator {
+            seed,
+            density: 0.5,
+            dont_care_ratio: 0.0,
+            cyclic_core: None,
+            essential_primes: None,
+            derivation_depth: None,
+            minimal_solutions: None,
+            max_attempts: 10_000,
+        }
+    }
+
+    /// Sets the probability of a minterm being ON
+    pub fn density(mut self, density: f64) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Sets the probability of a minterm, that isn't ON, being dont care
+    pub fn dont_care_ratio(mut self, dont_care_ratio: f64) -> Self {
+        self.dont_care_ratio = dont_care_ratio;
+        self
+    }
+
+    /// Requires the quine table to keep a cyclic core after the reduction, or to be solved by it
+    pub fn cyclic_core(mut self, cyclic_core: bool) -> Self {
+        self.cyclic_core = Some(cyclic_core);
+        self
+    }
+
+    /// Requires the number of essential prime implicants
+    pub fn essential_primes(mut self, essential_primes: usize) -> Self {
+        self.essential_primes = Some(essential_primes);
+        self
+    }
+
+    /// Requires the derivation depth, the length of the longest chain of merges deriving a die of the consensus table
+    pub fn derivation_depth(mut self, derivation_depth: usize) -> Self {
+        self.derivation_depth = Some(derivation_depth);
+        self
+    }
+
+    /// Requires the number of covers with the fewest primes, e.g. 1 for a unique solution. <br>
+    /// Only with this target the minimal solutions are enumerated and counted in the difficulty report
+    pub fn minimal_solutions(mut self, minimal_solutions: usize) -> Self {
+        self.minimal_solutions = Some(minimal_solutions);
+        self
+    }
+
+    /// Sets how many functions are drawn before giving up
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Draws functions until one has all target properties. <br>
+    /// Returns none if no function within the maximum number of attempts had them
+    pub fn generate(&self) -> Option<Exercise<N>> {
+        let mut seeds = SplitMix::new(self.seed);
+
+        for attempt in 0..self.max_attempts {
+            // the first attempt uses the seed itself, so an exercise can be regenerated from its seed
+            let seed = if attempt == 0 {
+                self.seed
+            } else {
+                seeds.next_u64()
+            };
+
+            if let Some(exercise) = self.attempt(seed) {
+                return Some(exercise);
+            }
+        }
+
+        None
+    }
+
+    fn attempt(&self, seed: u64) -> Option<Exercise<N>> {
+        let mut random = SplitMix::new(seed);
+
+        let mut on = Vec::new();
+        let mut dont_care = Vec::new();
+        for minterm in 0..(1 as Minterm) << N {
+            if random.next_f64() < self.density {
+                on.push(Die::from_minterm(minterm));
+            } else if random.next_f64() < self.dont_care_ratio {
+                dont_care.push(Die::from_minterm(minterm));
+            }
+        }
+
+        if on.is_empty() {
+            return None;
+        }
+
+        let mut consensus_table = ConesnsusTable::from_cover(on.clone(), dont_care.clone());
+        consensus_table.solve();
+
+        let mut quine_table = SecondQuineTable::from(&consensus_table);
+        quine_table.solve();
+
+        let mut difficulty = DifficultyReport::from_tables(&consensus_table, &quine_table);
+        if !self.matches(&difficulty) {
+            return None;
+        }
+
+        // the solutions are only enumerated once the cheaper targets matched
+        if let Some(count) = self.minimal_solutions {
+            difficulty.count_minimal_solutions(&mut quine_table);
+            if difficulty.minimal_solutions != Some(count) {
+                return None;
+            }
+        }
+
+        Some(Exercise {
+            seed,
+            on,
+            dont_care,
+            consensus_table,
+            quine_table,
+            difficulty,
+        })
+    }
+
+    /// Checks if the function has all target properties, a missing target matching every value
+    fn matches(&self, difficulty: &DifficultyReport) -> bool {
+        let cyclic_core = difficulty.cyclic_core.is_some();
+        self.cyclic_core.unwrap_or(cyclic_core) == cyclic_core
+            && self.essential_primes.unwrap_or(difficulty.essential_primes)
+                == difficulty.essential_primes
+            && self.derivation_depth.unwrap_or(difficulty.derivation_depth)
+                == difficulty.derivation_depth
+    }
+}
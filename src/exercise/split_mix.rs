@@ -0,0 +1,25 @@
+/// The SplitMix64 pseudo random number generator. <br>
+/// Small and fast, and the same seed yields the same numbers on every platform
+pub struct SplitMix {
+    state: u64,
+}
+
+impl SplitMix {
+    pub fn new(seed: u64) -> Self {
+        SplitMix { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `[0, 1)`
+    pub fn next_f64(&mut self) -> f64 {
+        // the upper 53 bits fill the mantissa of the float exactly
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -0,0 +1,140 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{Die, DieSide};
+
+/// A sum of products over the variables `x1` to `xN`, as students write it down. <br>
+/// Unlike a `Cover` the products are kept as written, so redundant products stay visible
+///
+/// Products are separated by `+`, `|` or `∨`, literals by whitespace, `*`, `&`, `·` or `∧`.
+/// A literal is negated by a leading `!`, `~`, `¬` or a trailing `'`.
+/// Variables are written as `x1`, `x_1` or `x_{1}`, and `0` and `1` are the constants
+///
+/// Example: <br>
+/// `x1 !x2 + x2 & x3'` is `(1 0 -)`, `(- 1 0)`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expression<const N: usize> {
+    pub products: Vec<Die<N>>,
+}
+
+impl<const N: usize> Expression<N> {
+    pub fn new(products: Vec<Die<N>>) -> Self {
+        Expression { products }
+    }
+
+    fn parse_product(product: &str) -> Result<Option<Die<N>>, String> {
+        let mut content = [DieSide::DontCare; N];
+        let mut chars = product.chars().peekable();
+        let mut empty = true;
+
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() || matches!(c, '*' | '&' | '·' | '∧') {
+                chars.next();
+                continue;
+            }
+
+            let mut negated = false;
+            while chars.next_if(|&c| matches!(c, '!' | '~' | '¬')).is_some() {
+                negated = !negated;
+            }
+
+            match chars.next() {
+                Some('0') if !negated => return Ok(None),
+                Some('1') if !negated => {
+                    empty = false;
+                    continue;
+                }
+                Some('x') => {}
+                Some(c) => return Err(format!("unexpected \"{c}\" in \"{product}\"")),
+                None => return Err(format!("\"{product}\" ends with a negation")),
+            }
+
+            chars.next_if_eq(&'_');
+            let braced = chars.next_if_eq(&'{').is_some();
+            let mut digits = String::new();
+            while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                digits.push(digit);
+            }
+            if braced && chars.next_if_eq(&'}').is_none() {
+                return Err(format!("missing \"}}\" in \"{product}\""));
+            }
+            while chars.next_if_eq(&'\'').is_some() {
+                negated = !negated;
+            }
+
+            let variable: usize = digits
+                .parse()
+                .map_err(|_| format!("missing variable number in \"{product}\""))?;
+            if variable == 0 || variable > N {
+                return Err(format!(
+                    "x{variable} is not one of the variables x1 to x{N}"
+                ));
+            }
+
+            let side = if negated { DieSide::Zero } else { DieSide::One };
+            match content[variable - 1] {
+                DieSide::DontCare => content[variable - 1] = side,
+                existing if existing != side => {
+                    return Err(format!(
+                        "\"{product}\" contains x{variable} and its negation"
+                    ))
+                }
+                _ => {}
+            }
+            empty = false;
+        }
+
+        if empty {
+            return Err("empty product".to_string());
+        }
+        Ok(Some(Die { content }))
+    }
+}
+
+impl<const N: usize> FromStr for Expression<N> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut products = Vec::new();
+        for product in s.split(['+', '|', '∨']) {
+            if let Some(die) = Self::parse_product(product)? {
+                products.push(die);
+            }
+        }
+
+        Ok(Expression { products })
+    }
+}
+
+impl<const N: usize> Display for Expression<N> {
+    /// Returns the expression in the notation it is parsed from, e.g. `x1 !x2 + x3`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.products.is_empty() {
+            return write!(f, "0");
+        }
+
+        let products: Vec<String> = self
+            .products
+            .iter()
+            .map(|die| {
+                let literals: Vec<String> = die
+                    .content
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, side)| match side {
+                        DieSide::One => Some(format!("x{}", i + 1)),
+                        DieSide::Zero => Some(format!("!x{}", i + 1)),
+                        DieSide::DontCare => None,
+                    })
+                    .collect();
+
+                if literals.is_empty() {
+                    "1".to_string()
+                } else {
+                    literals.join(" ")
+                }
+            })
+            .collect();
+
+        write!(f, "{}", products.join(" + "))
+    }
+}
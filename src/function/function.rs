@@ -0,0 +1,50 @@
+use crate::{ConesnsusTable, Die, DieSide, Minterm};
+
+/// A boolean function given by its ON, OFF and dont care minterms, each as a die
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Function<const N: usize> {
+    pub on: Vec<Die<N>>,
+    pub off: Vec<Die<N>>,
+    pub dont_care: Vec<Die<N>>,
+}
+
+impl<const N: usize> Function<N> {
+    /// Samples the closure for all assignments in ascending minterm order, the first variable being the most significant bit. <br>
+    /// `Some(true)` puts the assignment into the ON-set, `Some(false)` into the OFF-set and `None` makes it a dont care. <br>
+    /// Panics if N is bigger than 16, since all 2^N assignments are enumerated,
+    /// which would take over 65536 calls of the closure and as many dice. <br>
+    /// N is a parameter of the type rather than the method, so it is written `Function::<N>::from_fn`,
+    /// or inferred from an annotation or from a closure pattern like `|[a, b]|`
+    ///
+    /// Example: <br>
+    /// `Function::<2>::from_fn(|[a, b]| Some(a ^ b))` has the ON-set `(0 1)` and `(1 0)`
+    pub fn from_fn<F: FnMut([bool; N]) -> Option<bool>>(mut f: F) -> Self {
+        if N > 16 {
+            panic!(
+                "Functions are sampled for all assignments, so they can have at most 16 variables"
+            );
+        }
+
+        let mut function = Function {
+            on: Vec::new(),
+            off: Vec::new(),
+            dont_care: Vec::new(),
+        };
+        for minterm in 0..(1 as Minterm) << N {
+            let die = Die::from_minterm(minterm);
+            let bits = die.content.map(|side| side == DieSide::One);
+            match f(bits) {
+                Some(true) => function.on.push(die),
+                Some(false) => function.off.push(die),
+                None => function.dont_care.push(die),
+            }
+        }
+
+        function
+    }
+
+    /// Creates the consensus table for the ON-set and dont care set
+    pub fn consensus_table(&self) -> ConesnsusTable<N> {
+        ConesnsusTable::from_cover(self.on.clone(), self.dont_care.clone())
+    }
+}
@@ -0,0 +1,2 @@
+mod static_hazard;
+pub use static_hazard::StaticHazard;
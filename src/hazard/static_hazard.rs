@@ -0,0 +1,84 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{Cover, Die, DieSide};
+
+/// A static-1 hazard of a cover. <br>
+/// Two adjacent ON minterms form a hazard when no single product of the cover contains both of them,
+/// since the output may glitch to 0 while switching between the products. <br>
+/// The hazard is stored as the consensus die of the two products, whose DontCare at the changing variable
+/// spans the transitions and which contains at least one transition no single product covers
+///
+/// Example: <br>
+/// The cover `(1 1 -)`, `(0 - 1)` has the hazard `(- 1 1)` on variable 0
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StaticHazard<const N: usize> {
+    pub transition: Die<N>,
+    pub variable: usize,
+}
+
+impl<const N: usize> StaticHazard<N> {
+    /// Finds the static-1 hazards of the cover, one for each consensus die that isn't free of them. <br>
+    /// Every hazard lies in the consensus of two products that are opposed in exactly the changing variable.
+    /// A transition is free of the hazard when a product not depending on the variable covers it,
+    /// so the consensus die is free when those products together cover it. This never enumerates the transitions
+    pub fn find(cover: &[Die<N>]) -> Vec<StaticHazard<N>> {
+        let mut hazards = Vec::new();
+        let mut found = HashSet::new();
+
+        for (i, a) in cover.iter().enumerate() {
+            for b in &cover[i + 1..] {
+                let Some(consensus) = a.merge(*b) else {
+                    continue;
+                };
+
+                // the variable the merge removed is the only one set in both dice
+                let variable = (0..N)
+                    .find(|&v| {
+                        consensus.content[v] == DieSide::DontCare
+                            && a.content[v] != DieSide::DontCare
+                            && b.content[v] != DieSide::DontCare
+                    })
+                    .unwrap();
+
+                let hazard = StaticHazard {
+                    transition: consensus,
+                    variable,
+                };
+                if found.contains(&hazard) {
+                    continue;
+                }
+
+                let spanning = Cover::new(
+                    cover
+                        .iter()
+                        .filter(|product| product.content[variable] == DieSide::DontCare)
+                        .copied()
+                        .collect(),
+                );
+                if !spanning.cover_contains(&Cover::new(vec![consensus])) {
+                    found.insert(hazard);
+                    hazards.push(hazard);
+                }
+            }
+        }
+
+        hazards
+    }
+
+    /// Returns the two faces of the hazard that differ in the changing variable, the one with the variable at 0 first
+    pub fn minterms(&self) -> [Die<N>; 2] {
+        let mut low = self.transition;
+        let mut high = self.transition;
+        low.content[self.variable] = DieSide::Zero;
+        high.content[self.variable] = DieSide::One;
+        [low, high]
+    }
+}
+
+impl<const N: usize> Display for StaticHazard<N> {
+    /// Returns the hazard in the `(x1 ... xN) <-> (x1 ... xN)` notation
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [low, high] = self.minterms();
+        write!(f, "{low} <-> {high}")
+    }
+}
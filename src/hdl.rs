@@ -0,0 +1,6 @@
+mod hdl_language;
+pub use hdl_language::HdlLanguage;
+mod hdl_module;
+pub use hdl_module::HdlModule;
+mod hdl_output;
+pub use hdl_output::HdlOutput;
@@ -0,0 +1,7 @@
+/// The hardware description languages a module can be exported to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HdlLanguage {
+    Verilog,
+    SystemVerilog,
+    Vhdl,
+}
@@ -0,0 +1,618 @@
+use std::fmt::Write;
+
+use crate::{Die, DieSide, Minterm};
+
+use super::{HdlLanguage, HdlOutput};
+
+/// A synthesizable hardware module implementing minimized covers. <br>
+/// Every output becomes one continuous assignment over the inputs, which are named `x1` to `xN` by default
+///
+/// Example: <br>
+/// `HdlModule::new("majority").output(HdlOutput::new("f", cover)).render(HdlLanguage::Verilog)`
+pub struct HdlModule<const N: usize> {
+    name: String,
+    inputs: [String; N],
+    outputs: Vec<HdlOutput<N>>,
+}
+
+impl<const N: usize> HdlModule<N> {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        let name = name.into();
+        check_identifier(&name);
+
+        HdlModule {
+            name,
+            inputs: std::array::from_fn(|i| format!("x{}", i + 1)),
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Names the inputs, the first name belonging to the first variable of the dice
+    pub fn inputs<S: Into<String>>(mut self, inputs: [S; N]) -> Self {
+        self.inputs = inputs.map(Into::into);
+        self.inputs.iter().for_each(|input| check_identifier(input));
+        self.check_unique();
+        self
+    }
+
+    pub fn output(mut self, output: HdlOutput<N>) -> Self {
+        check_identifier(&output.name);
+        self.outputs.push(output);
+        self.check_unique();
+        self
+    }
+
+    /// Panics if an input, output or truth table output name is used twice, ignoring case like VHDL
+    fn check_unique(&self) {
+        let mut names: Vec<String> = self.inputs.to_vec();
+        for output in &self.outputs {
+            names.push(output.name.clone());
+            if output.function.is_some() {
+                names.push(output.table_name());
+            }
+        }
+
+        for (i, name) in names.iter().enumerate() {
+            if names[..i]
+                .iter()
+                .any(|other| other.eq_ignore_ascii_case(name))
+            {
+                panic!("\"{name}\" is used for more than one input or output");
+            }
+        }
+    }
+
+    pub fn render(&self, language: HdlLanguage) -> String {
+        match language {
+            HdlLanguage::Verilog | HdlLanguage::SystemVerilog => self.verilog(language),
+            HdlLanguage::Vhdl => self.vhdl(),
+        }
+    }
+
+    fn verilog(&self, language: HdlLanguage) -> String {
+        let (wire, reg) = match language {
+            HdlLanguage::SystemVerilog => ("logic", "logic"),
+            _ => ("wire", "reg"),
+        };
+
+        let mut ports: Vec<String> = self
+            .inputs
+            .iter()
+            .map(|input| format!("input {wire} {input}"))
+            .collect();
+        for output in &self.outputs {
+            ports.push(format!("output {wire} {}", output.name));
+            if output.function.is_some() {
+                ports.push(format!("output {reg} {}", output.table_name()));
+            }
+        }
+
+        let mut ret = format!("module {} (\n", self.name);
+        ret.push_str(&indented_list(&ports, ","));
+        ret.push_str(");\n");
+
+        for output in &self.outputs {
+            let _ = writeln!(
+                ret,
+                "    assign {} = {};",
+                output.name,
+                self.sum(&output.cover, language)
+            );
+        }
+
+        for output in &self.outputs {
+            let Some((on, dont_care)) = output.truth_table() else {
+                continue;
+            };
+
+            let name = output.table_name();
+            let always = match language {
+                HdlLanguage::SystemVerilog => "always_comb",
+                _ => "always @*",
+            };
+            let _ = writeln!(ret, "\n    {always} begin");
+            let _ = writeln!(ret, "        case ({{{}}})", self.inputs.join(", "));
+            for (minterm, value) in table_rows(&on, &dont_care) {
+                let value = match value {
+                    DieSide::One => "1'b1",
+                    _ => "1'bx",
+                };
+                let _ = writeln!(
+                    ret,
+                    "            {N}'b{}: {name} = {value};",
+                    bits::<N>(minterm)
+                );
+            }
+            let _ = writeln!(ret, "            default: {name} = 1'b0;");
+            ret.push_str("        endcase\n    end\n");
+        }
+
+        ret.push_str("endmodule\n");
+        ret
+    }
+
+    fn vhdl(&self) -> String {
+        let mut ports: Vec<String> = self
+            .inputs
+            .iter()
+            .map(|input| format!("{input} : in std_logic"))
+            .collect();
+        for output in &self.outputs {
+            ports.push(format!("{} : out std_logic", output.name));
+            if output.function.is_some() {
+                ports.push(format!("{} : out std_logic", output.table_name()));
+            }
+        }
+
+        let mut ret = String::from("library ieee;\nuse ieee.std_logic_1164.all;\n\n");
+        let _ = writeln!(ret, "entity {} is", self.name);
+        ret.push_str("    port (\n");
+        for line in indented_list(&ports, ";").lines() {
+            let _ = writeln!(ret, "    {line}");
+        }
+        ret.push_str("    );\n");
+        let _ = writeln!(ret, "end entity {};\n", self.name);
+
+        let _ = writeln!(ret, "architecture rtl of {} is", self.name);
+        ret.push_str("begin\n");
+        for output in &self.outputs {
+            let _ = writeln!(
+                ret,
+                "    {} <= {};",
+                output.name,
+                self.sum(&output.cover, HdlLanguage::Vhdl)
+            );
+        }
+
+        for output in &self.outputs {
+            let Some((on, dont_care)) = output.truth_table() else {
+                continue;
+            };
+
+            let name = output.table_name();
+            let _ = writeln!(ret, "\n    process ({})", self.inputs.join(", "));
+            let _ = writeln!(
+                ret,
+                "        variable inputs : std_logic_vector({} downto 0);",
+                N.saturating_sub(1)
+            );
+            ret.push_str("    begin\n");
+            let inputs = match &self.inputs[..] {
+                // a single std_logic has to be turned into a vector explicitly
+                [input] => format!("(0 => {input})"),
+                inputs => inputs.join(" & "),
+            };
+            let _ = writeln!(ret, "        inputs := {inputs};");
+            ret.push_str("        case inputs is\n");
+            for (minterm, value) in table_rows(&on, &dont_care) {
+                let value = match value {
+                    DieSide::One => "'1'",
+                    _ => "'-'",
+                };
+                let _ = writeln!(
+                    ret,
+                    "            when \"{}\" => {name} <= {value};",
+                    bits::<N>(minterm)
+                );
+            }
+            let _ = writeln!(ret, "            when others => {name} <= '0';");
+            ret.push_str("        end case;\n    end process;\n");
+        }
+
+        ret.push_str("end architecture rtl;\n");
+        ret
+    }
+
+    /// Renders the cover as a sum of products, e.g. `(x1 & ~x2) | x3`
+    fn sum(&self, cover: &[Die<N>], language: HdlLanguage) -> String {
+        let (zero, one, and, or) = match language {
+            HdlLanguage::Vhdl => ("'0'", "'1'", " and ", " or "),
+            _ => ("1'b0", "1'b1", " & ", " | "),
+        };
+
+        if cover.is_empty() {
+            return zero.to_string();
+        }
+
+        let products: Vec<String> = cover
+            .iter()
+            .map(|die| {
+                let literals: Vec<String> = die
+                    .content
+                    .iter()
+                    .zip(&self.inputs)
+                    .filter_map(|(side, input)| match (side, language) {
+                        (DieSide::One, _) => Some(input.clone()),
+                        (DieSide::Zero, HdlLanguage::Vhdl) => Some(format!("not {input}")),
+                        (DieSide::Zero, _) => Some(format!("~{input}")),
+                        (DieSide::DontCare, _) => None,
+                    })
+                    .collect();
+
+                match literals.len() {
+                    0 => one.to_string(),
+                    1 => literals[0].clone(),
+                    _ if cover.len() == 1 => literals.join(and),
+                    _ => format!("({})", literals.join(and)),
+                }
+            })
+            .collect();
+
+        products.join(or)
+    }
+}
+
+/// Returns the minterms of the truth table that aren't 0 in ascending order, with their value
+fn table_rows(on: &[Minterm], dont_care: &[Minterm]) -> Vec<(Minterm, DieSide)> {
+    let mut rows: Vec<(Minterm, DieSide)> = on
+        .iter()
+        .map(|&minterm| (minterm, DieSide::One))
+        .chain(
+            dont_care
+                .iter()
+                .map(|&minterm| (minterm, DieSide::DontCare)),
+        )
+        .collect();
+    rows.sort_by_key(|&(minterm, _)| minterm);
+    rows
+}
+
+/// Renders the minterm as binary number of N digits
+fn bits<const N: usize>(minterm: Minterm) -> String {
+    format!("{:0N$b}", minterm)
+}
+
+/// Renders one item per line, indented and separated
+fn indented_list(items: &[String], separator: &str) -> String {
+    let mut ret = String::new();
+    for (i, item) in items.iter().enumerate() {
+        let separator = if i + 1 < items.len() { separator } else { "" };
+        let _ = writeln!(ret, "    {item}{separator}");
+    }
+    ret
+}
+
+/// Keywords of Verilog, SystemVerilog and VHDL, and the names the rendered code uses itself
+const RESERVED_WORDS: &[&str] = &[
+    "abs",
+    "accept_on",
+    "access",
+    "after",
+    "alias",
+    "all",
+    "always",
+    "always_comb",
+    "always_ff",
+    "always_latch",
+    "and",
+    "architecture",
+    "array",
+    "assert",
+    "assign",
+    "assume",
+    "assume_guarantee",
+    "attribute",
+    "automatic",
+    "before",
+    "begin",
+    "bind",
+    "bins",
+    "binsof",
+    "bit",
+    "block",
+    "body",
+    "break",
+    "buf",
+    "buffer",
+    "bufif0",
+    "bufif1",
+    "bus",
+    "byte",
+    "case",
+    "casex",
+    "casez",
+    "cell",
+    "chandle",
+    "checker",
+    "class",
+    "clocking",
+    "cmos",
+    "component",
+    "config",
+    "configuration",
+    "const",
+    "constant",
+    "constraint",
+    "context",
+    "continue",
+    "cover",
+    "covergroup",
+    "coverpoint",
+    "cross",
+    "deassign",
+    "default",
+    "defparam",
+    "design",
+    "disable",
+    "disconnect",
+    "dist",
+    "do",
+    "downto",
+    "edge",
+    "else",
+    "elsif",
+    "end",
+    "endcase",
+    "endchecker",
+    "endclass",
+    "endclocking",
+    "endconfig",
+    "endfunction",
+    "endgenerate",
+    "endgroup",
+    "endinterface",
+    "endmodule",
+    "endpackage",
+    "endprimitive",
+    "endprogram",
+    "endproperty",
+    "endsequence",
+    "endspecify",
+    "endtable",
+    "endtask",
+    "entity",
+    "enum",
+    "event",
+    "eventually",
+    "exit",
+    "expect",
+    "export",
+    "extends",
+    "extern",
+    "fairness",
+    "file",
+    "final",
+    "first_match",
+    "for",
+    "force",
+    "foreach",
+    "forever",
+    "fork",
+    "forkjoin",
+    "function",
+    "generate",
+    "generic",
+    "genvar",
+    "global",
+    "group",
+    "guarded",
+    "highz0",
+    "highz1",
+    "ieee",
+    "if",
+    "iff",
+    "ifnone",
+    "ignore_bins",
+    "illegal_bins",
+    "implies",
+    "import",
+    "impure",
+    "in",
+    "incdir",
+    "include",
+    "inertial",
+    "initial",
+    "inout",
+    "input",
+    "inputs",
+    "inside",
+    "instance",
+    "int",
+    "integer",
+    "interface",
+    "intersect",
+    "is",
+    "join",
+    "join_any",
+    "join_none",
+    "label",
+    "large",
+    "let",
+    "liblist",
+    "library",
+    "linkage",
+    "literal",
+    "local",
+    "localparam",
+    "logic",
+    "longint",
+    "loop",
+    "macromodule",
+    "map",
+    "matches",
+    "medium",
+    "mod",
+    "modport",
+    "module",
+    "nand",
+    "negedge",
+    "new",
+    "next",
+    "nexttime",
+    "nmos",
+    "nor",
+    "noshowcancelled",
+    "not",
+    "notif0",
+    "notif1",
+    "null",
+    "of",
+    "on",
+    "open",
+    "or",
+    "others",
+    "out",
+    "output",
+    "package",
+    "packed",
+    "parameter",
+    "pmos",
+    "port",
+    "posedge",
+    "postponed",
+    "primitive",
+    "priority",
+    "procedure",
+    "process",
+    "program",
+    "property",
+    "protected",
+    "pull0",
+    "pull1",
+    "pulldown",
+    "pullup",
+    "pulsestyle_ondetect",
+    "pulsestyle_onevent",
+    "pure",
+    "rand",
+    "randc",
+    "randcase",
+    "randsequence",
+    "range",
+    "rcmos",
+    "real",
+    "realtime",
+    "record",
+    "ref",
+    "reg",
+    "register",
+    "reject",
+    "reject_on",
+    "release",
+    "rem",
+    "repeat",
+    "report",
+    "restrict",
+    "restrict_guarantee",
+    "return",
+    "rnmos",
+    "rol",
+    "ror",
+    "rpmos",
+    "rtl",
+    "rtran",
+    "rtranif0",
+    "rtranif1",
+    "s_always",
+    "s_eventually",
+    "s_nexttime",
+    "s_until",
+    "s_until_with",
+    "scalared",
+    "select",
+    "sequence",
+    "severity",
+    "shared",
+    "shortint",
+    "shortreal",
+    "showcancelled",
+    "signal",
+    "signed",
+    "sla",
+    "sll",
+    "small",
+    "soft",
+    "solve",
+    "specify",
+    "specparam",
+    "sra",
+    "srl",
+    "static",
+    "std_logic",
+    "std_logic_1164",
+    "string",
+    "strong",
+    "strong0",
+    "strong1",
+    "struct",
+    "subtype",
+    "super",
+    "supply0",
+    "supply1",
+    "sync_accept_on",
+    "sync_reject_on",
+    "table",
+    "tagged",
+    "task",
+    "then",
+    "this",
+    "throughout",
+    "time",
+    "timeprecision",
+    "timeunit",
+    "to",
+    "tran",
+    "tranif0",
+    "tranif1",
+    "transport",
+    "tri",
+    "tri0",
+    "tri1",
+    "triand",
+    "trior",
+    "trireg",
+    "type",
+    "typedef",
+    "unaffected",
+    "union",
+    "unique",
+    "unique0",
+    "units",
+    "unsigned",
+    "until",
+    "until_with",
+    "untyped",
+    "use",
+    "uwire",
+    "var",
+    "variable",
+    "vectored",
+    "virtual",
+    "vmode",
+    "void",
+    "vprop",
+    "vunit",
+    "wait",
+    "wait_order",
+    "wand",
+    "weak",
+    "weak0",
+    "weak1",
+    "when",
+    "while",
+    "wildcard",
+    "wire",
+    "with",
+    "within",
+    "wor",
+    "xnor",
+    "xor",
+];
+
+/// Panics if the name can't be used as identifier in every supported language. <br>
+/// VHDL ignores the case of identifiers, so reserved words are matched ignoring case too
+fn check_identifier(name: &str) {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.ends_with('_')
+        && !name.contains("__");
+
+    if !valid {
+        panic!("\"{name}\" is not a valid identifier, use letters, digits and single underscores");
+    }
+
+    if RESERVED_WORDS.contains(&name.to_ascii_lowercase().as_str()) {
+        panic!("\"{name}\" is a reserved word in Verilog or VHDL");
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{Die, Minterm, Numbering};
+
+/// An output of a hardware module, implemented by a minimized cover. <br>
+/// If the function the cover was minimized from is given, the module additionally gets
+/// an output `<name>_table` computing the function from its truth table, for equivalence checking
+pub struct HdlOutput<const N: usize> {
+    pub name: String,
+    pub cover: Vec<Die<N>>,
+    /// The ON and dont care dice of the function
+    pub function: Option<(Vec<Die<N>>, Vec<Die<N>>)>,
+}
+
+impl<const N: usize> HdlOutput<N> {
+    pub fn new<S: Into<String>>(name: S, cover: Vec<Die<N>>) -> Self {
+        HdlOutput {
+            name: name.into(),
+            cover,
+            function: None,
+        }
+    }
+
+    /// Adds the truth table of the function the cover was minimized from
+    pub fn with_truth_table(mut self, on: Vec<Die<N>>, dont_care: Vec<Die<N>>) -> Self {
+        if N > 16 {
+            panic!("Truth tables can only be exported for functions with at most 16 variables");
+        }
+        self.function = Some((on, dont_care));
+        self
+    }
+
+    /// Returns the ON and dont care minterms of the truth table, each in ascending order
+    pub(super) fn truth_table(&self) -> Option<(Vec<Minterm>, Vec<Minterm>)> {
+        let (on, dont_care) = self.function.as_ref()?;
+        let numbering = Numbering::default();
+        Some((
+            numbering.on_minterms(on, dont_care),
+            numbering.cover_minterms(dont_care),
+        ))
+    }
+
+    pub(super) fn table_name(&self) -> String {
+        format!("{}_table", self.name)
+    }
+}
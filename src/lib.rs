@@ -1,7 +1,31 @@
-pub use consensus_table::{ConesnsusTable, ConsensusTableEntry};
-pub use dice::{Die, DieSide};
-pub use quine_table::{SecondQuineTable, SecondQuineTableEntry};
+pub use codegen::CodeGenerator;
+pub use consensus_table::{ConesnsusTable, ConsensusTableEntry, DerivationGraph};
+pub use cost::{CostFunction, GateInputCount, LiteralCount, LiteralWeights, ProductCount};
+pub use cover::{CompiledCover, Cover};
+pub use dice::{Die, DieSide, Minterm, Minterms, Numbering};
+pub use difficulty::DifficultyReport;
+pub use espresso::{Espresso, EspressoStatistics};
+pub use exam::{ExamProblem, ExamSheet};
+pub use exercise::{Exercise, ExerciseGenerator};
+pub use expression::Expression;
+pub use function::Function;
+pub use hazard::StaticHazard;
+pub use hdl::{HdlLanguage, HdlModule, HdlOutput};
+pub use quine_table::{ReductionRound, SecondQuineTable, SecondQuineTableEntry};
+pub use validator::{AnswerValidator, Verdict};
 
+mod codegen;
 mod consensus_table;
+mod cost;
+mod cover;
 mod dice;
+mod difficulty;
+mod espresso;
+mod exam;
+mod exercise;
+mod expression;
+mod function;
+mod hazard;
+mod hdl;
 mod quine_table;
+mod validator;
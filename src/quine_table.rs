@@ -2,4 +2,9 @@ mod second_quine_table;
 pub use second_quine_table::SecondQuineTable;
 mod second_quine_table_entry;
 pub use second_quine_table_entry::SecondQuineTableEntry;
+mod reduction_round;
+pub use reduction_round::ReductionRound;
+mod bit_set;
+mod coverage_matrix;
+mod minimum_cover;
 mod second_quine_table_cell;
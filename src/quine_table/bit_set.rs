@@ -0,0 +1,88 @@
+/// A fixed size set of indices, stored as one bit per index
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// Creates a set for the indices `0..len` without any index in it
+    pub fn new(len: usize) -> Self {
+        BitSet {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// Creates a set for the indices `0..len` with every index in it
+    pub fn full(len: usize) -> Self {
+        let mut set = BitSet {
+            words: vec![u64::MAX; len.div_ceil(64)],
+            len,
+        };
+        // the bits of the last word beyond the length stay unset
+        let last_bits = len % 64;
+        if last_bits != 0 {
+            if let Some(last) = set.words.last_mut() {
+                *last = (1 << last_bits) - 1;
+            }
+        }
+        set
+    }
+
+    pub fn insert(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (index % 64);
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        self.words[index / 64] &= !(1 << (index % 64));
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Returns the number of indices in the set
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        BitSet {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| a & b)
+                .collect(),
+            len: self.len,
+        }
+    }
+
+    /// Checks if every index of this set, that is also in the mask, is in the other set
+    pub fn is_subset_within(&self, other: &BitSet, mask: &BitSet) -> bool {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .zip(&mask.words)
+            .all(|((a, b), m)| a & m & !b == 0)
+    }
+
+    /// Iterates the indices of the set in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use crate::Minterm;
+
+use super::{
+    bit_set::BitSet,
+    second_quine_table_cell::{CellState, SecondQuineTableCell},
+};
+
+/// The sparse matrix of which row covers which term of a quine table. <br>
+/// Terms are mapped to column indices, so the terms don't have to be contiguous.
+/// The coverage is stored twice, as the columns of each row and as the rows of each column
+pub struct CoverageMatrix {
+    columns_by_term: HashMap<Minterm, usize>,
+    rows: Vec<BitSet>,
+    columns: Vec<BitSet>,
+    /// The state of every cell the reduction touched, all other cells are `CellState::NA`
+    states: HashMap<(usize, usize), CellState>,
+}
+
+impl CoverageMatrix {
+    /// Creates the matrix for the sorted terms, with the terms each row covers
+    pub fn new<I: Iterator<Item = Minterm>>(
+        terms: &[Minterm],
+        coverage: impl Iterator<Item = I>,
+    ) -> Self {
+        let columns_by_term: HashMap<Minterm, usize> = terms
+            .iter()
+            .enumerate()
+            .map(|(column, &term)| (term, column))
+            .collect();
+
+        let mut rows = Vec::new();
+        for covers in coverage {
+            let mut row = BitSet::new(terms.len());
+            for term in covers {
+                if let Some(&column) = columns_by_term.get(&term) {
+                    row.insert(column);
+                }
+            }
+            rows.push(row);
+        }
+
+        let mut columns = vec![BitSet::new(rows.len()); terms.len()];
+        for (i, row) in rows.iter().enumerate() {
+            for column in row.iter() {
+                columns[column].insert(i);
+            }
+        }
+
+        CoverageMatrix {
+            columns_by_term,
+            rows,
+            columns,
+            states: HashMap::new(),
+        }
+    }
+
+    /// Returns the column index of the term, or none if the term doesn't have a column
+    pub fn column(&self, term: Minterm) -> Option<usize> {
+        self.columns_by_term.get(&term).copied()
+    }
+
+    /// Returns the columns the row covers
+    pub fn row(&self, row: usize) -> &BitSet {
+        &self.rows[row]
+    }
+
+    /// Returns the rows covering the column
+    pub fn column_rows(&self, column: usize) -> &BitSet {
+        &self.columns[column]
+    }
+
+    pub fn cell(&self, row: usize, column: usize) -> SecondQuineTableCell {
+        SecondQuineTableCell {
+            entry: self.rows[row].contains(column),
+            state: self
+                .states
+                .get(&(row, column))
+                .copied()
+                .unwrap_or(CellState::NA),
+        }
+    }
+
+    pub fn set_state(&mut self, row: usize, column: usize, state: CellState) {
+        self.states.insert((row, column), state);
+    }
+
+    pub fn reset_states(&mut self) {
+        self.states.clear();
+    }
+}
@@ -0,0 +1,128 @@
+/// Branch and bound search for the cheapest sets of rows covering all columns of a covering problem
+pub struct MinimumCover {
+    /// The columns each row covers
+    rows: Vec<Vec<usize>>,
+    /// The rows covering each column
+    covering: Vec<Vec<usize>>,
+    costs: Vec<usize>,
+    columns: usize,
+    /// The maximum number of cheapest covers to collect
+    limit: usize,
+    excluded: Vec<bool>,
+    best: Vec<Vec<usize>>,
+    best_cost: Option<usize>,
+}
+
+impl MinimumCover {
+    pub fn new(rows: Vec<Vec<usize>>, costs: Vec<usize>, columns: usize, limit: usize) -> Self {
+        let mut covering = vec![Vec::new(); columns];
+        for (row, covers) in rows.iter().enumerate() {
+            for &column in covers {
+                covering[column].push(row);
+            }
+        }
+
+        MinimumCover {
+            covering,
+            excluded: vec![false; rows.len()],
+            rows,
+            costs,
+            columns,
+            limit,
+            best: Vec::new(),
+            best_cost: None,
+        }
+    }
+
+    /// Returns the indices of the rows of up to `limit` different cheapest covers together with their cost,
+    /// or none if the rows can't cover all columns
+    pub fn solve(mut self) -> Option<(Vec<Vec<usize>>, usize)> {
+        let mut covered = vec![0; self.columns];
+        self.search(&mut Vec::new(), &mut covered, 0);
+        self.best_cost.map(|cost| (self.best, cost))
+    }
+
+    fn search(&mut self, selected: &mut Vec<usize>, covered: &mut [usize], cost: usize) {
+        // branch on the uncovered column with the fewest rows, since it has to be covered anyway
+        let column = (0..self.columns)
+            .filter(|&column| covered[column] == 0)
+            .min_by_key(|&column| self.rows_covering(column).count());
+
+        let Some(column) = column else {
+            self.add_cover(selected, cost);
+            return;
+        };
+
+        if let Some(best) = self.best_cost {
+            let bound = cost + self.lower_bound(covered);
+            // covers as expensive as the best ones are only needed while the limit isn't reached
+            if bound > best || (bound == best && self.best.len() >= self.limit) {
+                return;
+            }
+        }
+
+        let mut candidates: Vec<usize> = self.rows_covering(column).collect();
+        candidates.sort_by_key(|&row| self.costs[row]);
+
+        for &row in &candidates {
+            for &column in &self.rows[row] {
+                covered[column] += 1;
+            }
+            selected.push(row);
+
+            self.search(selected, covered, cost + self.costs[row]);
+
+            selected.pop();
+            for &column in &self.rows[row] {
+                covered[column] -= 1;
+            }
+
+            // every cover containing this row was searched, so the next branches must not find them again
+            self.excluded[row] = true;
+        }
+
+        for &row in &candidates {
+            self.excluded[row] = false;
+        }
+    }
+
+    fn add_cover(&mut self, selected: &[usize], cost: usize) {
+        match self.best_cost {
+            Some(best) if cost > best => return,
+            Some(best) if cost == best => {
+                if self.best.len() >= self.limit {
+                    return;
+                }
+            }
+            _ => {
+                self.best.clear();
+                self.best_cost = Some(cost);
+            }
+        }
+
+        let mut rows = selected.to_vec();
+        rows.sort();
+        self.best.push(rows);
+    }
+
+    /// Every uncovered column needs at least its cheapest row, so the most expensive of those is a lower bound
+    fn lower_bound(&self, covered: &[usize]) -> usize {
+        (0..self.columns)
+            .filter(|&column| covered[column] == 0)
+            .map(|column| {
+                self.rows_covering(column)
+                    .map(|row| self.costs[row])
+                    .min()
+                    .unwrap_or(0)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn rows_covering(&self, column: usize) -> impl Iterator<Item = usize> + '_ {
+        self.covering[column]
+            .iter()
+            .copied()
+            .filter(move |&row| !self.excluded[row])
+    }
+}
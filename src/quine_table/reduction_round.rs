@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+use super::SecondQuineTable;
+
+/// A single round of the reduction of a solved quine table,
+/// rendered the way the reduction is done by hand
+pub struct ReductionRound<'a, const N: usize> {
+    table: &'a SecondQuineTable<N>,
+    round: usize,
+}
+
+impl<'a, const N: usize> ReductionRound<'a, N> {
+    pub(super) fn new(table: &'a SecondQuineTable<N>, round: usize) -> Self {
+        ReductionRound { table, round }
+    }
+}
+
+impl<const N: usize> Display for ReductionRound<'_, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.table.fmt_round(f, self.round)
+    }
+}
@@ -1,63 +1,601 @@
-use std::fmt::Display;
+use std::{collections::HashSet, fmt::Display};
 
-use crate::Die;
+use crate::{CostFunction, Die, Minterm, Numbering, ProductCount};
 
 use super::{
-    second_quine_table_cell::SecondQuineTableCell, second_quine_table_entry::SecondQuineTableEntry,
+    bit_set::BitSet,
+    coverage_matrix::CoverageMatrix,
+    minimum_cover::MinimumCover,
+    reduction_round::ReductionRound,
+    second_quine_table_cell::{CellState, ColumnReason, RowReason},
+    second_quine_table_entry::SecondQuineTableEntry,
 };
 
 const DIE_TITLE: &str = "Die";
+const REASON_TITLE: &str = "Reason";
 
 pub struct SecondQuineTable<const N: usize> {
     entries: Vec<SecondQuineTableEntry<N>>,
-    table: Vec<Vec<SecondQuineTableCell>>,
+    numbering: Numbering<N>,
+    terms: Vec<Minterm>,
+    table: CoverageMatrix,
+    solutions: Vec<Vec<usize>>,
+    cost: Option<usize>,
+    /// The reduction round and reason each removed row was removed for
+    row_reasons: Vec<Option<(usize, RowReason)>>,
+    column_reasons: Vec<Option<(usize, ColumnReason)>>,
+    rounds: usize,
 }
 
 impl<const N: usize> SecondQuineTable<N> {
-    /// Solves the quine table for its prime implicants
-    pub fn solve(&mut self) {}
+    /// Creates the table for the prime implicants of a function with dont cares. <br>
+    /// Terms covered by the dont care dice don't have to be covered, so they don't get a column
+    pub fn new(dice: Vec<Die<N>>, dont_care: Vec<Die<N>>) -> Self {
+        SecondQuineTable::with_numbering(dice, dont_care, Numbering::default())
+    }
 
-    fn entry_to_string(
+    /// Creates the table like `new`, but numbers the term columns with the passed numbering convention
+    pub fn with_numbering(
+        dice: Vec<Die<N>>,
+        dont_care: Vec<Die<N>>,
+        numbering: Numbering<N>,
+    ) -> Self {
+        let dont_care_terms: HashSet<Minterm> = dont_care
+            .iter()
+            .flat_map(|die| numbering.minterms(die))
+            .collect();
+
+        SecondQuineTable::build(
+            dice.into_iter()
+                .map(|die| SecondQuineTableEntry::with_numbering(die, numbering))
+                .collect(),
+            &dont_care_terms,
+            numbering,
+        )
+    }
+
+    /// Builds the table, numbering the terms of all entries with the numbering convention of the table
+    fn build(
+        entries: Vec<SecondQuineTableEntry<N>>,
+        dont_care_terms: &HashSet<Minterm>,
+        numbering: Numbering<N>,
+    ) -> Self {
+        let entries: Vec<SecondQuineTableEntry<N>> = entries
+            .into_iter()
+            .map(|entry| SecondQuineTableEntry::with_numbering(entry.die, numbering))
+            .collect();
+
+        let mut terms: Vec<Minterm> = entries
+            .iter()
+            .flat_map(SecondQuineTableEntry::terms)
+            .filter(|term| !dont_care_terms.contains(term))
+            .collect();
+        terms.sort();
+        terms.dedup();
+
+        let table = CoverageMatrix::new(&terms, entries.iter().map(SecondQuineTableEntry::terms));
+
+        SecondQuineTable {
+            row_reasons: vec![None; entries.len()],
+            column_reasons: vec![None; terms.len()],
+            entries,
+            numbering,
+            terms,
+            table,
+            solutions: Vec::new(),
+            cost: None,
+            rounds: 0,
+        }
+    }
+
+    /// Solves the quine table for a cover with the fewest prime implicants
+    pub fn solve(&mut self) {
+        self.solve_with(&ProductCount);
+    }
+
+    /// Solves the quine table for a cover of the prime implicants that is minimal under the cost function. <br>
+    /// Essential rows and dominance are applied first, the remaining cyclic core is solved with branch and bound
+    pub fn solve_with<C: CostFunction<N>>(&mut self, cost: &C) {
+        self.solve_limited(cost, 1);
+    }
+
+    /// Solves the quine table for all covers with the fewest prime implicants, stopping after `limit` covers. <br>
+    /// A limit of 0 keeps no covers, only the optimal cost
+    pub fn solve_all(&mut self, limit: usize) {
+        self.solve_all_with(&ProductCount, limit);
+    }
+
+    /// Solves the quine table for all covers that are minimal under the cost function, stopping after `limit` covers. <br>
+    /// Rows are only removed by dominance if they are more expensive, so no equally cheap alternative gets lost.
+    /// A limit of 0 keeps no covers, only the optimal cost
+    pub fn solve_all_with<C: CostFunction<N>>(&mut self, cost: &C, limit: usize) {
+        self.solve_limited(cost, limit);
+    }
+
+    /// Returns the dice of the first minimal cover found by the last solve
+    pub fn solution(&self) -> Vec<Die<N>> {
+        self.solutions().next().unwrap_or_default()
+    }
+
+    /// Returns the row indices of the first minimal cover found by the last solve
+    pub fn solution_rows(&self) -> &[usize] {
+        self.solutions.first().map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the dice of every minimal cover found by the last solve
+    pub fn solutions(&self) -> impl Iterator<Item = Vec<Die<N>>> + '_ {
+        self.solutions
+            .iter()
+            .map(|rows| rows.iter().map(|&row| self.entries[row].die).collect())
+    }
+
+    /// Returns the number of minimal covers found by the last solve, which is at most the passed limit
+    pub fn solution_count(&self) -> usize {
+        self.solutions.len()
+    }
+
+    /// Returns the cost of the minimal cover, or none if the table wasn't solved yet
+    pub fn optimal_cost(&self) -> Option<usize> {
+        self.cost
+    }
+
+    fn solve_limited<C: CostFunction<N>>(&mut self, cost: &C, limit: usize) {
+        let costs: Vec<usize> = self
+            .entries
+            .iter()
+            .map(|entry| cost.die_cost(&entry.die))
+            .collect();
+
+        self.table.reset_states();
+        self.row_reasons = vec![None; self.entries.len()];
+        self.column_reasons = vec![None; self.terms.len()];
+
+        let mut rows = BitSet::full(self.entries.len());
+        let mut columns = BitSet::full(self.terms.len());
+        let mut essentials = Vec::new();
+
+        let mut round = 0;
+        loop {
+            let essential =
+                self.select_essential_rows(round, &mut rows, &mut columns, &mut essentials);
+            let dominated =
+                self.remove_dominated_rows(round, &mut rows, &columns, &costs, limit == 1);
+            let dominating = self.remove_dominating_columns(round, &rows, &mut columns);
+
+            if !essential && !dominated && !dominating {
+                break;
+            }
+            round += 1;
+        }
+        self.rounds = round;
+
+        // solve the remaining cyclic core
+        let core_rows: Vec<usize> = rows.iter().collect();
+        let mut core_columns = vec![0; self.terms.len()];
+        for (i, column) in columns.iter().enumerate() {
+            core_columns[column] = i;
+        }
+        let core = MinimumCover::new(
+            core_rows
+                .iter()
+                .map(|&row| {
+                    self.table
+                        .row(row)
+                        .intersection(&columns)
+                        .iter()
+                        .map(|column| core_columns[column])
+                        .collect()
+                })
+                .collect(),
+            core_rows.iter().map(|&row| costs[row]).collect(),
+            columns.count(),
+            limit,
+        );
+
+        match core.solve() {
+            Some((core_solutions, _)) => {
+                let solutions = core_solutions
+                    .iter()
+                    .map(|core_solution| {
+                        let mut solution = essentials.clone();
+                        solution.extend(core_solution.iter().map(|&i| core_rows[i]));
+                        solution.sort();
+                        solution
+                    })
+                    .collect();
+                let (solutions, optimal_cost) = self.cheapest_covers(cost, solutions, limit);
+                self.cost = Some(optimal_cost);
+                self.solutions = solutions;
+            }
+            None => {
+                self.cost = None;
+                self.solutions = Vec::new();
+            }
+        }
+    }
+
+    /// Keeps the covers that are cheapest under the cost function, stopping after `limit` covers. <br>
+    /// The branch and bound minimizes the sum of the dice costs, but a cost function may charge a single product less,
+    /// so every row covering all terms on its own is compared as well
+    fn cheapest_covers<C: CostFunction<N>>(
         &self,
-        entry: &SecondQuineTableEntry<N>,
-        pad: usize,
-        terms: &Vec<usize>,
-    ) -> String {
-        let mut ret = format!(" {} ", entry.die);
+        cost: &C,
+        mut covers: Vec<Vec<usize>>,
+        limit: usize,
+    ) -> (Vec<Vec<usize>>, usize) {
+        if !self.terms.is_empty() {
+            for row in 0..self.entries.len() {
+                if self.table.row(row).count() == self.terms.len() && !covers.contains(&vec![row]) {
+                    covers.push(vec![row]);
+                }
+            }
+        }
+
+        let cover_cost = |rows: &Vec<usize>| {
+            let dice: Vec<Die<N>> = rows.iter().map(|&row| self.entries[row].die).collect();
+            cost.cover_cost(&dice)
+        };
+        let optimal_cost = covers.iter().map(cover_cost).min().unwrap_or(0);
+        covers.retain(|rows| cover_cost(rows) == optimal_cost);
+        covers.truncate(limit);
+
+        (covers, optimal_cost)
+    }
 
-        for &term in terms {
-            if entry.covers(term) {
-                ret.push_str(format!("┃ {:pad$} ", 'X').as_str());
-            } else {
-                ret.push_str(format!("┃ {:pad$} ", ' ').as_str())
+    /// Selects all rows that are the only row covering an active column,
+    /// removing the rows and all columns they cover from the table
+    fn select_essential_rows(
+        &mut self,
+        round: usize,
+        rows: &mut BitSet,
+        columns: &mut BitSet,
+        solution: &mut Vec<usize>,
+    ) -> bool {
+        let mut changed = false;
+
+        for column in 0..self.terms.len() {
+            if !columns.contains(column) {
+                continue;
+            }
+
+            let covering = self.table.column_rows(column).intersection(rows);
+            if covering.count() != 1 {
+                continue;
+            }
+            let Some(row) = covering.iter().next() else {
+                continue;
+            };
+
+            rows.remove(row);
+            solution.push(row);
+            self.row_reasons[row] = Some((round, RowReason::Essential));
+
+            let covered: Vec<usize> = self.table.row(row).intersection(columns).iter().collect();
+            for other in covered {
+                columns.remove(other);
+                self.column_reasons[other] = Some((round, ColumnReason::Covered(row)));
+                self.table.set_state(row, other, CellState::Prime);
+
+                let covered_rows: Vec<usize> = self
+                    .table
+                    .column_rows(other)
+                    .intersection(rows)
+                    .iter()
+                    .collect();
+                for covered_row in covered_rows {
+                    self.table.set_state(covered_row, other, CellState::Covered);
+                }
             }
+            self.table.set_state(row, column, CellState::Essential);
+            changed = true;
         }
 
-        ret
+        changed
     }
-}
 
-impl<const N: usize> From<Vec<SecondQuineTableEntry<N>>> for SecondQuineTable<N> {
-    fn from(entries: Vec<SecondQuineTableEntry<N>>) -> Self {
-        let mut terms = Vec::new();
-        for entry in &entries {
-            for &term in &entry.covers {
-                if !terms.contains(&term) {
-                    terms.push(term);
+    /// Removes all rows without active columns,
+    /// and all rows whose active columns are also covered by another row that is at most as expensive. <br>
+    /// Of two equal rows the later one is removed.
+    /// If `ties` is false the other row must be cheaper, keeping rows that could be part of another minimal cover
+    fn remove_dominated_rows(
+        &mut self,
+        round: usize,
+        rows: &mut BitSet,
+        columns: &BitSet,
+        costs: &[usize],
+        ties: bool,
+    ) -> bool {
+        let mut changed = false;
+
+        for row in 0..self.entries.len() {
+            if !rows.contains(row) {
+                continue;
+            }
+
+            let active = self.table.row(row).intersection(columns);
+            let Some(first) = active.iter().next() else {
+                rows.remove(row);
+                self.row_reasons[row] = Some((round, RowReason::Empty));
+                changed = true;
+                continue;
+            };
+
+            // a dominating row has to cover the first column as well
+            let dominated = self
+                .table
+                .column_rows(first)
+                .intersection(rows)
+                .iter()
+                .find(|&other| {
+                    if other == row || costs[other] > costs[row] {
+                        return false;
+                    }
+                    if !ties && costs[other] == costs[row] {
+                        return false;
+                    }
+                    if !active.is_subset_within(self.table.row(other), columns) {
+                        return false;
+                    }
+
+                    let equal = costs[other] == costs[row]
+                        && self.table.row(other).intersection(columns) == active;
+                    !equal || other < row
+                });
+
+            if let Some(other) = dominated {
+                rows.remove(row);
+                self.row_reasons[row] = Some((round, RowReason::Dominated(other)));
+                for column in active.iter() {
+                    self.table.set_state(row, column, CellState::DominatingRow);
                 }
+                changed = true;
             }
         }
-        terms.sort();
 
-        let mut table = vec![vec![SecondQuineTableCell::default(); entries.len()]; terms.len()];
+        changed
+    }
 
-        for (i, entry) in entries.iter().enumerate() {
-            for &term in &entry.covers {
-                table[i][term].entry = true;
+    /// Removes all columns that are covered whenever another column is covered. <br>
+    /// Of two equal columns the later one is removed
+    fn remove_dominating_columns(
+        &mut self,
+        round: usize,
+        rows: &BitSet,
+        columns: &mut BitSet,
+    ) -> bool {
+        let mut changed = false;
+
+        for column in 0..self.terms.len() {
+            if !columns.contains(column) {
+                continue;
+            }
+
+            let active = self.table.column_rows(column).intersection(rows);
+            let dominating = columns.iter().find(|&other| {
+                if other == column {
+                    return false;
+                }
+                let other_rows = self.table.column_rows(other);
+                if !other_rows.is_subset_within(&active, rows) {
+                    return false;
+                }
+
+                let equal = other_rows.intersection(rows) == active;
+                !equal || other < column
+            });
+
+            if let Some(other) = dominating {
+                columns.remove(column);
+                self.column_reasons[column] = Some((round, ColumnReason::Dominating(other)));
+                for row in active.iter() {
+                    self.table
+                        .set_state(row, column, CellState::DominatingColumn);
+                }
+                changed = true;
             }
         }
 
-        SecondQuineTable { entries, table }
+        changed
+    }
+
+    /// Returns the dice of the rows
+    pub fn dice(&self) -> Vec<Die<N>> {
+        self.entries.iter().map(|entry| entry.die).collect()
+    }
+
+    /// Returns the terms of the columns in ascending order
+    pub fn terms(&self) -> &[Minterm] {
+        &self.terms
+    }
+
+    /// Returns the symbol the cell is rendered as after the last solve
+    pub fn symbol(&self, row: usize, column: usize) -> char {
+        self.table.cell(row, column).symbol()
+    }
+
+    /// Returns the numbering convention of the term columns
+    pub fn numbering(&self) -> Numbering<N> {
+        self.numbering
+    }
+
+    /// Checks if the row covers the term. Dont care terms are never covered, since they don't have a column
+    pub fn covers(&self, row: usize, term: Minterm) -> bool {
+        self.table
+            .column(term)
+            .is_some_and(|column| self.table.row(row).contains(column))
+    }
+
+    /// Returns the number of reduction rounds the last solve needed before only the cyclic core was left
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Checks if columns were left after the reduction of the last solve,
+    /// which then had to be covered by branch and bound. <br>
+    /// An unsolved table has no cyclic core yet
+    pub fn has_cyclic_core(&self) -> bool {
+        self.cost.is_some() && self.column_reasons.iter().any(Option::is_none)
+    }
+
+    /// Returns the number of rows and columns left after the reduction of the last solve
+    pub fn cyclic_core_size(&self) -> (usize, usize) {
+        (
+            self.row_reasons
+                .iter()
+                .filter(|reason| reason.is_none())
+                .count(),
+            self.column_reasons
+                .iter()
+                .filter(|reason| reason.is_none())
+                .count(),
+        )
+    }
+
+    /// Returns the rows selected as essential in the first reduction round of the last solve. <br>
+    /// Rows that only became essential after removing dominated rows or columns are not included
+    pub fn essential_rows(&self) -> Vec<usize> {
+        (0..self.entries.len())
+            .filter(|&row| matches!(self.row_reasons[row], Some((0, RowReason::Essential))))
+            .collect()
+    }
+
+    /// Returns every reduction round of the last solve, followed by the cyclic core if one is left. <br>
+    /// Each round renders the table as it was at the start of the round, marking what the round removed
+    pub fn reduction_rounds(&self) -> impl Iterator<Item = ReductionRound<'_, N>> {
+        let rounds = if self.has_cyclic_core() {
+            self.rounds + 1
+        } else {
+            self.rounds
+        };
+
+        (0..rounds).map(|round| ReductionRound::new(self, round))
+    }
+
+    /// Renders the table at the start of the round. <br>
+    /// Rows get the reason they were removed for appended, removed columns are listed below the table
+    pub(super) fn fmt_round(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        round: usize,
+    ) -> std::fmt::Result {
+        // rows and columns removed in an earlier round are no longer part of the table
+        let rows: Vec<usize> = (0..self.entries.len())
+            .filter(|&row| !matches!(self.row_reasons[row], Some((removed, _)) if removed < round))
+            .collect();
+        let columns: Vec<usize> = (0..self.terms.len())
+            .filter(|&column| {
+                !matches!(self.column_reasons[column], Some((removed, _)) if removed < round)
+            })
+            .collect();
+
+        if round < self.rounds {
+            writeln!(f, "Round {}", round + 1)?;
+        } else {
+            writeln!(f, "Cyclic core")?;
+        }
+
+        let (die_pad, pad) = self.paddings();
+
+        write!(f, " {:die_pad$} ", DIE_TITLE)?;
+        for &column in &columns {
+            write!(f, "┃ {:pad$} ", self.terms[column])?;
+        }
+        writeln!(f, "┃ {REASON_TITLE}")?;
+
+        write!(f, "{}", "━".repeat(die_pad + 2))?;
+        for _ in &columns {
+            write!(f, "╋{}", "━".repeat(pad + 2))?;
+        }
+        writeln!(f, "╋{}", "━".repeat(REASON_TITLE.len() + 1))?;
+
+        for &row in &rows {
+            let reason = match self.row_reasons[row] {
+                Some((removed, RowReason::Essential)) if removed == round => {
+                    "essential".to_string()
+                }
+                Some((removed, RowReason::Empty)) if removed == round => {
+                    "nothing left to cover".to_string()
+                }
+                Some((removed, RowReason::Dominated(other))) if removed == round => {
+                    format!("dominated by {}", self.entries[other].die)
+                }
+                _ => String::new(),
+            };
+
+            writeln!(
+                f,
+                "{}┃ {reason}",
+                self.entry_to_string(row, die_pad, pad, &columns, Some(round))
+            )?;
+        }
+
+        for &column in &columns {
+            match self.column_reasons[column] {
+                Some((removed, ColumnReason::Covered(row))) if removed == round => writeln!(
+                    f,
+                    " {} covered by {}",
+                    self.terms[column], self.entries[row].die
+                )?,
+                Some((removed, ColumnReason::Dominating(other))) if removed == round => {
+                    writeln!(f, " {} dominates {}", self.terms[column], self.terms[other])?
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the width of the die column and of the term columns
+    fn paddings(&self) -> (usize, usize) {
+        let die_pad = self
+            .entries
+            .first()
+            .map_or(0, |entry| entry.die.to_string().len())
+            .max(DIE_TITLE.len());
+        let pad = self.terms.last().map_or(1, |term| format!("{term}").len());
+
+        (die_pad, pad)
+    }
+
+    /// Renders a row of the table. <br>
+    /// Without a round every cell shows the state the last solve left it in,
+    /// with a round only cells removed in that round are marked
+    fn entry_to_string(
+        &self,
+        row: usize,
+        die_pad: usize,
+        pad: usize,
+        columns: &[usize],
+        round: Option<usize>,
+    ) -> String {
+        let mut ret = format!(" {:die_pad$} ", self.entries[row].die.to_string());
+
+        for &column in columns {
+            let cell = self.table.cell(row, column);
+            let marked = match round {
+                None => true,
+                Some(round) => {
+                    self.row_reasons[row].is_some_and(|(removed, _)| removed == round)
+                        || self.column_reasons[column].is_some_and(|(removed, _)| removed == round)
+                }
+            };
+
+            let symbol = match cell.entry {
+                true if marked => cell.symbol(),
+                true => 'X',
+                false => ' ',
+            };
+            ret.push_str(format!("┃ {:pad$} ", symbol).as_str());
+        }
+
+        ret
+    }
+}
+
+impl<const N: usize> From<Vec<SecondQuineTableEntry<N>>> for SecondQuineTable<N> {
+    fn from(entries: Vec<SecondQuineTableEntry<N>>) -> Self {
+        SecondQuineTable::build(entries, &HashSet::new(), Numbering::default())
     }
 }
 
@@ -74,9 +612,7 @@ impl<const N: usize> From<Vec<Die<N>>> for SecondQuineTable<N> {
 
 impl<const N: usize> Display for SecondQuineTable<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut terms = Vec::new();
-
-        let mut die_pad = DIE_TITLE.len();
+        let (die_pad, pad) = self.paddings();
 
         if self.entries.is_empty() {
             // only print header since the table is empty
@@ -85,39 +621,233 @@ impl<const N: usize> Display for SecondQuineTable<N> {
             return Ok(());
         }
 
-        let die_print_len = self.entries.first().unwrap().die.to_string().len();
-        if die_pad < die_print_len {
-            die_pad = die_print_len;
-        }
-
-        for entry in &self.entries {
-            for &term in &entry.covers {
-                if !terms.contains(&term) {
-                    terms.push(term);
-                }
-            }
-        }
-
-        terms.sort();
-
-        let pad = format!("{}", terms.last().unwrap()).len();
-
         write!(f, " {:die_pad$} ", DIE_TITLE)?;
-        for term in &terms {
+        for term in &self.terms {
             write!(f, "┃ {:pad$} ", term)?;
         }
         writeln!(f)?;
 
         write!(f, "{}", "━".repeat(die_pad + 2))?;
-        for _ in &terms {
+        for _ in &self.terms {
             write!(f, "╋{}", "━".repeat(pad + 2))?;
         }
         writeln!(f)?;
 
-        for entry in &self.entries {
-            writeln!(f, "{}", self.entry_to_string(&entry, pad, &terms))?;
+        let columns: Vec<usize> = (0..self.terms.len()).collect();
+        for row in 0..self.entries.len() {
+            writeln!(
+                f,
+                "{}",
+                self.entry_to_string(row, die_pad, pad, &columns, None)
+            )?;
         }
 
         Ok(())
     }
 }
+
+/// The JSON schema of a quine table and its last solution. <br>
+/// Every row stores the state of each of its cells, null where the row doesn't cover the term
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SecondQuineTableData<const N: usize> {
+    numbering: Numbering<N>,
+    columns: Vec<ColumnData>,
+    rows: Vec<RowData<N>>,
+    solutions: Vec<Vec<usize>>,
+    cost: Option<usize>,
+    rounds: usize,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ColumnData {
+    /// Minterms can exceed the integers JSON readers handle exactly, so they are stored as strings
+    #[serde(with = "minterm_string")]
+    term: Minterm,
+    removed: Option<Removal<ColumnReason>>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RowData<const N: usize> {
+    die: Die<N>,
+    cells: Vec<Option<CellState>>,
+    removed: Option<Removal<RowReason>>,
+}
+
+#[cfg(feature = "serde")]
+mod minterm_string {
+    use crate::Minterm;
+
+    pub fn serialize<S: serde::Serializer>(
+        term: &Minterm,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(term)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Minterm, D::Error> {
+        use serde::{de::Error, Deserialize};
+
+        let term = String::deserialize(deserializer)?;
+        term.parse()
+            .map_err(|_| D::Error::custom(format!("{term} is no minterm")))
+    }
+}
+
+/// The reduction round a row or column was removed in and why
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Removal<R> {
+    round: usize,
+    reason: R,
+}
+
+#[cfg(feature = "serde")]
+impl<R> From<(usize, R)> for Removal<R> {
+    fn from((round, reason): (usize, R)) -> Self {
+        Removal { round, reason }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for SecondQuineTable<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SecondQuineTableData {
+            numbering: self.numbering,
+            columns: self
+                .terms
+                .iter()
+                .zip(&self.column_reasons)
+                .map(|(&term, reason)| ColumnData {
+                    term,
+                    removed: reason.map(Removal::from),
+                })
+                .collect(),
+            rows: self
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(row, entry)| RowData {
+                    die: entry.die,
+                    cells: (0..self.terms.len())
+                        .map(|column| {
+                            let cell = self.table.cell(row, column);
+                            cell.entry.then_some(cell.state)
+                        })
+                        .collect(),
+                    removed: self.row_reasons[row].map(Removal::from),
+                })
+                .collect(),
+            solutions: self.solutions.clone(),
+            cost: self.cost,
+            rounds: self.rounds,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for SecondQuineTable<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let data = SecondQuineTableData::<N>::deserialize(deserializer)?;
+
+        let terms: Vec<Minterm> = data.columns.iter().map(|column| column.term).collect();
+        if terms.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(D::Error::custom("terms must be sorted and unique"));
+        }
+
+        let numbering = data.numbering;
+        let table = CoverageMatrix::new(
+            &terms,
+            data.rows.iter().map(|row| numbering.minterms(&row.die)),
+        );
+
+        let mut quine_table = SecondQuineTable {
+            entries: data
+                .rows
+                .iter()
+                .map(|row| SecondQuineTableEntry::with_numbering(row.die, numbering))
+                .collect(),
+            numbering,
+            terms,
+            table,
+            solutions: data.solutions,
+            cost: data.cost,
+            row_reasons: Vec::new(),
+            column_reasons: data
+                .columns
+                .iter()
+                .map(|column| column.removed.as_ref().map(|r| (r.round, r.reason)))
+                .collect(),
+            rounds: data.rounds,
+        };
+
+        let rows = quine_table.entries.len();
+        for (i, row) in data.rows.iter().enumerate() {
+            if row.cells.len() != quine_table.terms.len() {
+                return Err(D::Error::custom(format!(
+                    "row {i} must have a cell for each of the {} terms",
+                    quine_table.terms.len()
+                )));
+            }
+
+            for (column, cell) in row.cells.iter().enumerate() {
+                if cell.is_some() != quine_table.table.row(i).contains(column) {
+                    return Err(D::Error::custom(format!(
+                        "row {i} must have a cell exactly for the terms {} covers",
+                        row.die
+                    )));
+                }
+                if let Some(state) = cell {
+                    quine_table.table.set_state(i, column, *state);
+                }
+            }
+        }
+
+        let referenced_rows =
+            data.rows
+                .iter()
+                .filter_map(|row| match row.removed.as_ref()?.reason {
+                    RowReason::Dominated(other) => Some(other),
+                    _ => None,
+                })
+                .chain(data.columns.iter().filter_map(
+                    |column| match column.removed.as_ref()?.reason {
+                        ColumnReason::Covered(row) => Some(row),
+                        ColumnReason::Dominating(_) => None,
+                    },
+                ))
+                .chain(quine_table.solutions.iter().flatten().copied());
+        for row in referenced_rows {
+            if row >= rows {
+                return Err(D::Error::custom(format!("row {row} doesn't exist")));
+            }
+        }
+
+        for column in &data.columns {
+            if let Some(Removal {
+                reason: ColumnReason::Dominating(other),
+                ..
+            }) = column.removed
+            {
+                if other >= quine_table.terms.len() {
+                    return Err(D::Error::custom(format!("column {other} doesn't exist")));
+                }
+            }
+        }
+
+        quine_table.row_reasons = data
+            .rows
+            .iter()
+            .map(|row| row.removed.as_ref().map(|r| (r.round, r.reason)))
+            .collect();
+
+        Ok(quine_table)
+    }
+}
@@ -1,7 +1,7 @@
 #[derive(Clone, Copy)]
 pub struct SecondQuineTableCell {
     pub entry: bool,
-    state: CellState,
+    pub state: CellState,
 }
 
 impl Default for SecondQuineTableCell {
@@ -13,10 +13,68 @@ impl Default for SecondQuineTableCell {
     }
 }
 
-#[derive(Clone, Copy)]
+impl SecondQuineTableCell {
+    /// Returns the symbol the cell is rendered as, marking how the reduction removed it
+    pub fn symbol(&self) -> char {
+        if !self.entry {
+            return ' ';
+        }
+
+        match self.state {
+            CellState::NA | CellState::Prime => 'X',
+            CellState::Essential => '⊛',
+            CellState::Covered | CellState::DominatingColumn => '│',
+            CellState::DominatingRow => '─',
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum CellState {
+    #[cfg_attr(feature = "serde", serde(rename = "na"))]
     NA,
+    /// The only entry of its column, which makes the row an essential prime
+    Essential,
+    /// Another entry of an essential prime's row
     Prime,
+    /// An entry of a column an essential prime already covers
+    Covered,
+    /// An entry of a row removed, because another row dominates it
     DominatingRow,
+    /// An entry of a column removed, because it dominates another column
     DominatingColumn,
 }
+
+/// Why a row was removed from the table during the reduction
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum RowReason {
+    Essential,
+    /// All terms of the row are already covered
+    Empty,
+    /// Dominated by the row
+    Dominated(usize),
+}
+
+/// Why a column was removed from the table during the reduction
+#[derive(Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ColumnReason {
+    /// Covered by the essential row
+    Covered(usize),
+    /// Dominates the column
+    Dominating(usize),
+}
@@ -0,0 +1,4 @@
+mod answer_validator;
+pub use answer_validator::AnswerValidator;
+mod verdict;
+pub use verdict::Verdict;
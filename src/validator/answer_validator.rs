@@ -0,0 +1,118 @@
+use crate::{ConesnsusTable, CostFunction, Cover, Die, Expression, ProductCount, SecondQuineTable};
+
+use super::Verdict;
+
+/// Checks submitted covers against a problem. <br>
+/// The prime implicants, the OFF-set and the cost of a cover with the fewest products are computed once,
+/// so many answers can be checked cheaply
+pub struct AnswerValidator<const N: usize> {
+    on: Cover<N>,
+    dont_care: Cover<N>,
+    off: Cover<N>,
+    consensus_table: ConesnsusTable<N>,
+    primes: Vec<Die<N>>,
+    /// The cost of a cover with the fewest products, or none if the quine table couldn't be solved
+    optimal_cost: Option<usize>,
+}
+
+impl<const N: usize> AnswerValidator<N> {
+    pub fn new(dice: Vec<Die<N>>, dont_care: Vec<Die<N>>) -> Self {
+        let on = Cover::new(dice.clone());
+        let dont_care_cover = Cover::new(dont_care.clone());
+        let off = on.union(&dont_care_cover).complement();
+
+        let mut consensus_table = ConesnsusTable::from_cover(dice, dont_care);
+        consensus_table.solve();
+        let primes = consensus_table.prime_implicants();
+
+        let mut quine_table = SecondQuineTable::from(&consensus_table);
+        quine_table.solve();
+
+        AnswerValidator {
+            on,
+            dont_care: dont_care_cover,
+            off,
+            optimal_cost: quine_table.optimal_cost(),
+            consensus_table,
+            primes,
+        }
+    }
+
+    /// Checks the answer, judging minimality by the number of products
+    pub fn check(&self, answer: &[Die<N>]) -> Verdict<N> {
+        self.verdict(answer, &ProductCount, self.optimal_cost)
+    }
+
+    /// Checks the answer, judging minimality by the cost function. <br>
+    /// A quine table has to be built and solved for the cost function first, so this is slower than `check`
+    pub fn check_with<C: CostFunction<N>>(&self, answer: &[Die<N>], cost: &C) -> Verdict<N> {
+        let mut quine_table = SecondQuineTable::from(&self.consensus_table);
+        quine_table.solve_with(cost);
+        self.verdict(answer, cost, quine_table.optimal_cost())
+    }
+
+    /// Parses the answer as an expression like `x1 !x2 + x3` and checks it
+    pub fn check_expression(&self, answer: &str) -> Result<Verdict<N>, String> {
+        let expression: Expression<N> = answer.parse()?;
+        Ok(self.check(&expression.products))
+    }
+
+    /// Parses the answer as an expression like `x1 !x2 + x3` and checks it, judging minimality by the cost function
+    pub fn check_expression_with<C: CostFunction<N>>(
+        &self,
+        answer: &str,
+        cost: &C,
+    ) -> Result<Verdict<N>, String> {
+        let expression: Expression<N> = answer.parse()?;
+        Ok(self.check_with(&expression.products, cost))
+    }
+
+    fn verdict<C: CostFunction<N>>(
+        &self,
+        answer: &[Die<N>],
+        cost: &C,
+        optimal_cost: Option<usize>,
+    ) -> Verdict<N> {
+        let answer_cover = Cover::new(answer.to_vec());
+
+        let uncovered = self.on.sharp(&answer_cover.union(&self.dont_care)).dice;
+        let off_set = answer_cover.intersection(&self.off).dice;
+
+        let non_primes = answer
+            .iter()
+            .filter(|die| !self.primes.contains(die))
+            .filter_map(|die| {
+                self.primes
+                    .iter()
+                    .find(|prime| prime.covers(*die))
+                    .map(|prime| (*die, *prime))
+            })
+            .collect();
+
+        let redundant = answer
+            .iter()
+            .enumerate()
+            .filter(|&(i, die)| {
+                let others: Vec<Die<N>> = answer
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, other)| *other)
+                    .collect();
+                Cover::new(others)
+                    .union(&self.dont_care)
+                    .cover_contains(&Cover::new(vec![*die]))
+            })
+            .map(|(_, die)| *die)
+            .collect();
+
+        Verdict {
+            uncovered,
+            off_set,
+            non_primes,
+            redundant,
+            cost: cost.cover_cost(answer),
+            optimal_cost,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+
+use crate::Die;
+
+/// The result of checking a submitted cover against a problem. <br>
+/// Every failed check keeps the dice proving the failure, each die standing for the counterexample minterms it covers
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Verdict<const N: usize> {
+    /// ON minterms the answer doesn't cover
+    pub uncovered: Vec<Die<N>>,
+    /// OFF minterms the answer covers
+    pub off_set: Vec<Die<N>>,
+    /// Products of the answer that are implicants but no primes, each with a prime containing it
+    pub non_primes: Vec<(Die<N>, Die<N>)>,
+    /// Products of the answer the other products already cover
+    pub redundant: Vec<Die<N>>,
+    /// The cost of the answer
+    pub cost: usize,
+    /// The cost of a minimal cover of the problem, or none if the quine table couldn't be solved
+    pub optimal_cost: Option<usize>,
+}
+
+impl<const N: usize> Verdict<N> {
+    pub fn covers_on_set(&self) -> bool {
+        self.uncovered.is_empty()
+    }
+
+    pub fn avoids_off_set(&self) -> bool {
+        self.off_set.is_empty()
+    }
+
+    /// Checks if the answer implements the function
+    pub fn is_valid(&self) -> bool {
+        self.covers_on_set() && self.avoids_off_set()
+    }
+
+    /// Checks if every product of the answer is a prime implicant
+    pub fn is_prime(&self) -> bool {
+        self.avoids_off_set() && self.non_primes.is_empty()
+    }
+
+    pub fn is_irredundant(&self) -> bool {
+        self.redundant.is_empty()
+    }
+
+    /// Checks if the answer implements the function as cheap as possible. <br>
+    /// Without an optimal cost no answer is minimal
+    pub fn is_minimal(&self) -> bool {
+        self.is_valid() && self.optimal_cost == Some(self.cost)
+    }
+}
+
+impl<const N: usize> Display for Verdict<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let dice = |dice: &[Die<N>]| {
+            dice.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        let check = |ok: bool| if ok { "yes" } else { "no" };
+
+        write!(f, "Covers ON-set:  {}", check(self.covers_on_set()))?;
+        if !self.covers_on_set() {
+            write!(f, ", misses {}", dice(&self.uncovered))?;
+        }
+        writeln!(f)?;
+
+        write!(f, "Avoids OFF-set: {}", check(self.avoids_off_set()))?;
+        if !self.avoids_off_set() {
+            write!(f, ", covers {}", dice(&self.off_set))?;
+        }
+        writeln!(f)?;
+
+        write!(f, "Primes:         {}", check(self.is_prime()))?;
+        for (die, prime) in &self.non_primes {
+            write!(f, ", {die} ⊂ {prime}")?;
+        }
+        writeln!(f)?;
+
+        write!(f, "Irredundant:    {}", check(self.is_irredundant()))?;
+        if !self.is_irredundant() {
+            write!(f, ", {} can be removed", dice(&self.redundant))?;
+        }
+        writeln!(f)?;
+
+        match self.optimal_cost {
+            Some(optimal_cost) => writeln!(
+                f,
+                "Minimal:        {}, cost {} of {}",
+                check(self.is_minimal()),
+                self.cost,
+                optimal_cost
+            ),
+            None => writeln!(
+                f,
+                "Minimal:        unknown, cost {}, no minimal cover was found",
+                self.cost
+            ),
+        }
+    }
+}
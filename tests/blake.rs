@@ -0,0 +1,55 @@
+use consensus_solver::{ConesnsusTable, Die};
+
+#[test]
+pub fn general_consensus() {
+    let a: Die<4> = Die::new("1-10");
+    let b: Die<4> = Die::new("001-");
+
+    assert_eq!(a.consensus(b), Some(Die::new("-010")));
+    assert_eq!(a.intersect(b), None);
+    assert_eq!(
+        Die::<3>::new("1--").intersect(Die::new("-01")),
+        Some(Die::new("101"))
+    );
+}
+
+#[test]
+pub fn simplified_input() {
+    let mut blake: Vec<Die<4>> = ConesnsusTable::blake_canonical_form(vec![
+        Die::new("1-1-"),
+        Die::new("0--1"),
+        Die::new("1111"),
+        Die::new("--00"),
+    ]);
+    blake.sort_by_key(|die| die.as_binary_string());
+
+    assert_eq!(
+        blake,
+        vec![
+            Die::new("--00"),
+            Die::new("--11"),
+            Die::new("0--1"),
+            Die::new("0-0-"),
+            Die::new("1--0"),
+            Die::new("1-1-"),
+        ]
+    );
+}
+
+#[test]
+pub fn absorbed_input() {
+    let dice: Vec<Die<2>> = vec![Die::new("1-"), Die::new("11")];
+
+    // new keeps the input as it is, only solving marks dice as covered
+    let table = ConesnsusTable::new(dice.clone(), Vec::new());
+    assert_eq!(table.prime_implicants(), dice);
+
+    let table = ConesnsusTable::from_cover(dice.clone(), Vec::new());
+    assert_eq!(table.prime_implicants(), vec![Die::new("1-")]);
+    assert_eq!(table.entries()[1].covered, Some(0));
+
+    // solving doesn't absorb the inputs either
+    let mut table = ConesnsusTable::new(dice.clone(), Vec::new());
+    table.solve();
+    assert_eq!(table.prime_implicants(), dice);
+}
@@ -0,0 +1,176 @@
+use std::{env, fs, path::PathBuf, process::Command};
+
+use consensus_solver::{CodeGenerator, Die};
+
+fn on() -> Vec<Die<3>> {
+    vec![Die::new("000"), Die::new("001"), Die::new("011")]
+}
+
+fn dont_care() -> Vec<Die<3>> {
+    vec![Die::new("111")]
+}
+
+fn generator() -> CodeGenerator<3> {
+    CodeGenerator::new("f", vec![Die::new("00-"), Die::new("-11")])
+}
+
+/// Returns a fresh directory for the generated sources of the test
+fn directory(test: &str) -> PathBuf {
+    let directory = env::temp_dir().join(format!("consensus_solver_{}_{test}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+#[test]
+fn rust() {
+    assert_eq!(
+        generator().rust(),
+        concat!(
+            "/// (0 0 -) + (- 1 1)\n",
+            "pub const fn f(x: u32) -> bool {\n",
+            "    (x & 0b110) == 0b000\n",
+            "        || (x & 0b011) == 0b011\n",
+            "}\n",
+        )
+    );
+}
+
+#[test]
+fn c() {
+    assert_eq!(
+        generator().c(),
+        concat!(
+            "#include <stdbool.h>\n",
+            "#include <stdint.h>\n",
+            "\n",
+            "/* (0 0 -) + (- 1 1) */\n",
+            "bool f(uint32_t x) {\n",
+            "    return (x & 0x6u) == 0x0u\n",
+            "        || (x & 0x3u) == 0x3u;\n",
+            "}\n",
+        )
+    );
+}
+
+#[test]
+fn constants() {
+    assert!(CodeGenerator::<2>::new("zero", vec![])
+        .rust()
+        .contains("    false\n"));
+    assert!(CodeGenerator::<2>::new("one", vec![Die::new("--")])
+        .c()
+        .contains("    return true;\n"));
+}
+
+#[test]
+fn no_variables() {
+    let test = CodeGenerator::<0>::new("one", vec![Die::new("")]).rust_test(&[Die::new("")], &[]);
+    assert!(test.contains("    for x in 0..=0 {\n"));
+    assert!(CodeGenerator::<3>::new("f", vec![])
+        .rust_test(&[], &[])
+        .contains("    for x in 0..=(u32::MAX >> (32 - 3)) {\n"));
+}
+
+#[test]
+#[should_panic(expected = "not a valid function name")]
+fn invalid_name() {
+    CodeGenerator::<2>::new("2fast", vec![]);
+}
+
+#[test]
+#[should_panic(expected = "reserved word")]
+fn rust_keyword() {
+    CodeGenerator::<2>::new("fn", vec![]);
+}
+
+#[test]
+#[should_panic(expected = "reserved word")]
+fn c_keyword() {
+    CodeGenerator::<2>::new("unsigned", vec![]);
+}
+
+#[test]
+#[should_panic(expected = "reserved word")]
+fn harness_name() {
+    CodeGenerator::<2>::new("covered", vec![]);
+}
+
+#[test]
+fn rust_harness() {
+    let directory = directory("rust");
+    let source = directory.join("generated.rs");
+    let binary = directory.join("generated");
+
+    for (cover, passes) in [
+        (vec![Die::new("00-"), Die::new("-11")], true),
+        (vec![Die::new("00-")], false),
+    ] {
+        let generator = CodeGenerator::new("f", cover);
+        fs::write(
+            &source,
+            format!(
+                "{}\n{}",
+                generator.rust(),
+                generator.rust_test(&on(), &dont_care())
+            ),
+        )
+        .unwrap();
+
+        let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let compiled = Command::new(rustc)
+            .args(["--test", "--edition", "2021", "-o"])
+            .arg(&binary)
+            .arg(&source)
+            .status()
+            .unwrap();
+        assert!(compiled.success());
+
+        let output = Command::new(&binary).output().unwrap();
+        assert_eq!(output.status.success(), passes);
+    }
+
+    fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn c_harness() {
+    // the harness is only compiled where a C compiler is installed
+    let Ok(cc) = Command::new("cc").arg("--version").output() else {
+        eprintln!("skipping the C harness, no C compiler \"cc\" was found");
+        return;
+    };
+    assert!(cc.status.success());
+
+    let directory = directory("c");
+    let source = directory.join("generated.c");
+    let binary = directory.join("generated");
+
+    for (cover, passes) in [
+        (vec![Die::new("00-"), Die::new("-11")], true),
+        (vec![Die::new("0--")], false),
+    ] {
+        let generator = CodeGenerator::new("f", cover);
+        fs::write(
+            &source,
+            format!(
+                "{}\n{}",
+                generator.c(),
+                generator.c_test(&on(), &dont_care())
+            ),
+        )
+        .unwrap();
+
+        let compiled = Command::new("cc")
+            .args(["-std=c99", "-Wall", "-Werror", "-o"])
+            .arg(&binary)
+            .arg(&source)
+            .status()
+            .unwrap();
+        assert!(compiled.success());
+
+        let output = Command::new(&binary).output().unwrap();
+        assert_eq!(output.status.success(), passes);
+    }
+
+    fs::remove_dir_all(directory).unwrap();
+}
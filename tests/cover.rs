@@ -0,0 +1,67 @@
+use consensus_solver::{Cover, Die};
+
+#[test]
+pub fn off_set() {
+    let on: Cover<3> = Cover::new(vec![Die::new("1-1"), Die::new("01-")]);
+    let dont_care: Cover<3> = Cover::new(vec![Die::new("000")]);
+
+    let off = on.union(&dont_care).complement();
+
+    assert!(off.intersection(&on).is_empty());
+    assert!(off.intersection(&dont_care).is_empty());
+    assert_eq!(
+        off.union(&on).union(&dont_care).complement(),
+        Cover::empty()
+    );
+}
+
+#[test]
+pub fn operations() {
+    let a: Cover<3> = Cover::new(vec![Die::new("1--")]);
+    let b: Cover<3> = Cover::new(vec![Die::new("-1-")]);
+
+    assert_eq!(a.intersection(&b), Cover::new(vec![Die::new("11-")]));
+    assert_eq!(a.sharp(&b), Cover::new(vec![Die::new("10-")]));
+    assert_eq!(
+        a.xor(&b),
+        Cover::new(vec![Die::new("10-"), Die::new("01-")])
+    );
+    assert_eq!(
+        a.union(&b).cofactor(0, false),
+        Cover::new(vec![Die::new("-1-")])
+    );
+    assert_eq!(a.complement().to_string(), "(0 - -)");
+}
+
+#[test]
+fn compiled_cover() {
+    let cover: Cover<4> = Cover::new(vec![Die::new("1-0-"), Die::new("-011"), Die::new("0000")]);
+    let compiled = cover.compile();
+
+    let assignments: Vec<u64> = (0..16).collect();
+    let expected: Vec<bool> = assignments
+        .iter()
+        .map(|&assignment| {
+            cover
+                .dice
+                .iter()
+                .any(|die| die.covers_minterm(assignment as u128))
+        })
+        .collect();
+
+    assert_eq!(compiled.eval_many(&assignments), expected);
+    assert!(compiled.eval(0b1101));
+    assert!(!compiled.eval(0b0111));
+    assert!(!Cover::<4>::empty().compile().eval(0));
+    assert!(Cover::<4>::universe().compile().eval(0b1010));
+}
+
+#[test]
+fn compiled_wide_cover() {
+    let die: Die<64> = Die::new(format!("1{}0", "-".repeat(62)));
+    let compiled = Cover::new(vec![die]).compile();
+
+    assert!(compiled.eval(1 << 63));
+    assert!(!compiled.eval((1 << 63) | 1));
+    assert!(!compiled.eval(0));
+}
@@ -0,0 +1,62 @@
+use consensus_solver::{ConesnsusTable, Die};
+
+fn table() -> ConesnsusTable<3> {
+    let mut table = ConesnsusTable::new(
+        vec![Die::new("011"), Die::new("-10")],
+        vec![Die::new("111")],
+    );
+    table.solve();
+    table
+}
+
+#[test]
+fn dot() {
+    assert_eq!(
+        table().derivation_graph().dot(),
+        concat!(
+            "digraph derivation {\n",
+            "    node [shape=box, fontname=\"monospace\"];\n",
+            "    n0 [label=\"0: (1 1 1)\", style=dashed];\n",
+            "    n1 [label=\"1: (0 1 1)\"];\n",
+            "    n2 [label=\"2: (- 1 0)\"];\n",
+            "    n3 [label=\"3: (- 1 1)\"];\n",
+            "    n4 [label=\"4: (- 1 -)\", peripheries=2];\n",
+            "    n1 -> n3;\n",
+            "    n0 -> n3;\n",
+            "    n3 -> n4;\n",
+            "    n2 -> n4;\n",
+            "    n0 -> n3 [style=dashed, label=\"covered by\"];\n",
+            "    n1 -> n3 [style=dashed, label=\"covered by\"];\n",
+            "    n2 -> n4 [style=dashed, label=\"covered by\"];\n",
+            "    n3 -> n4 [style=dashed, label=\"covered by\"];\n",
+            "}\n",
+        )
+    );
+}
+
+#[test]
+fn mermaid() {
+    assert_eq!(
+        table().derivation_graph().mermaid(),
+        concat!(
+            "flowchart TD\n",
+            "    n0[\"0: (1 1 1)\"]\n",
+            "    n1[\"1: (0 1 1)\"]\n",
+            "    n2[\"2: (- 1 0)\"]\n",
+            "    n3[\"3: (- 1 1)\"]\n",
+            "    n4[\"4: (- 1 -)\"]\n",
+            "    n1 --> n3\n",
+            "    n0 --> n3\n",
+            "    n3 --> n4\n",
+            "    n2 --> n4\n",
+            "    n0 -. covered by .-> n3\n",
+            "    n1 -. covered by .-> n3\n",
+            "    n2 -. covered by .-> n4\n",
+            "    n3 -. covered by .-> n4\n",
+            "    classDef prime stroke-width:3px\n",
+            "    class n4 prime\n",
+            "    classDef dontCare stroke-dasharray:5 5\n",
+            "    class n0 dontCare\n",
+        )
+    );
+}
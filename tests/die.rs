@@ -0,0 +1,27 @@
+use consensus_solver::{die, Die, DieSide};
+
+const PRIME: Die<4> = Die::from_binary_string("1-0-");
+
+#[test]
+fn die_macro() {
+    let die = die!("01-");
+
+    assert_eq!(die, Die::<3>::new("01-"));
+    assert_eq!(
+        die.content,
+        [DieSide::Zero, DieSide::One, DieSide::DontCare]
+    );
+    assert_eq!(die!("").to_string(), "()");
+}
+
+#[test]
+fn const_die() {
+    assert_eq!(PRIME, Die::new("1-0-"));
+    assert_eq!(PRIME.to_string(), "(1 - 0 -)");
+}
+
+#[test]
+#[should_panic(expected = "Invalid value, must only be 0s, 1s and -")]
+fn invalid_character() {
+    Die::<3>::from_binary_string("01x");
+}
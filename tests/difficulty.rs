@@ -0,0 +1,70 @@
+use consensus_solver::{ConesnsusTable, Die, DifficultyReport, SecondQuineTable};
+
+fn report(minterms: &[u128]) -> DifficultyReport {
+    let mut table: ConesnsusTable<3> = ConesnsusTable::new(
+        minterms
+            .iter()
+            .map(|&minterm| Die::from_minterm(minterm))
+            .collect(),
+        vec![],
+    );
+    table.solve();
+    DifficultyReport::new(&table)
+}
+
+#[test]
+fn cyclic_function() {
+    let report = report(&[0, 1, 2, 5, 6, 7]);
+
+    assert_eq!(
+        report,
+        DifficultyReport {
+            generated_rows: 6,
+            derivation_depth: 2,
+            primes: 6,
+            essential_primes: 0,
+            cyclic_core: Some((6, 6)),
+            minimal_solutions: Some(2),
+        }
+    );
+    assert_eq!(
+        report.to_string(),
+        concat!(
+            "Generated rows:    6\n",
+            "Derivation depth:  2\n",
+            "Primes:            6\n",
+            "Essential primes:  0\n",
+            "Cyclic core:       6 x 6\n",
+            "Minimal solutions: 2\n",
+        )
+    );
+}
+
+#[test]
+fn essential_function() {
+    let report = report(&[0, 1, 3, 7]);
+
+    assert_eq!(report.primes, 3);
+    assert_eq!(report.essential_primes, 2);
+    assert_eq!(report.cyclic_core, None);
+    assert_eq!(report.minimal_solutions, Some(1));
+}
+
+#[test]
+fn uncounted_solutions() {
+    let mut consensus_table: ConesnsusTable<3> =
+        ConesnsusTable::new([0, 1, 2, 5, 6, 7].map(Die::from_minterm).to_vec(), vec![]);
+    consensus_table.solve();
+    let mut quine_table = SecondQuineTable::from(&consensus_table);
+    quine_table.solve();
+
+    let mut report = DifficultyReport::from_tables(&consensus_table, &quine_table);
+    assert_eq!(report.minimal_solutions, None);
+    assert!(report
+        .to_string()
+        .ends_with("Minimal solutions: not counted\n"));
+
+    report.count_minimal_solutions(&mut quine_table);
+    assert_eq!(report, DifficultyReport::new(&consensus_table));
+    assert_eq!(quine_table.solution_count(), 1);
+}
@@ -1,4 +1,4 @@
-use consensus_solver::{self, ConesnsusTable, ConsensusTableEntry, Die};
+use consensus_solver::{self, ConesnsusTable, ConsensusTableEntry, Die, SecondQuineTable};
 
 #[test]
 pub fn unused_dont_care() {
@@ -76,3 +76,25 @@ pub fn unused_merged_dont_care() {
 
     assert!(compare.to_string() == table.to_string());
 }
+
+#[test]
+pub fn dont_care_columns() {
+    let mut table: ConesnsusTable<5> = ConesnsusTable::new(
+        vec![Die::new("-1000")],
+        vec![Die::new("00000"), Die::new("10000")],
+    );
+    table.solve();
+
+    let mut quine = SecondQuineTable::from(&table);
+    quine.solve();
+
+    assert_eq!(quine.solution(), vec![Die::new("--000")]);
+    assert_eq!(
+        quine.to_string(),
+        concat!(
+            " Die         ┃  8 ┃ 24 \n",
+            "━━━━━━━━━━━━━╋━━━━╋━━━━\n",
+            " (- - 0 0 0) ┃ ⊛  ┃ X  \n",
+        )
+    );
+}
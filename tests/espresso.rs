@@ -0,0 +1,73 @@
+use consensus_solver::{Cover, Die, Espresso};
+
+#[test]
+pub fn minterms() {
+    let dice = vec![
+        Die::new("0000"),
+        Die::new("0001"),
+        Die::new("0010"),
+        Die::new("0011"),
+        Die::new("0111"),
+        Die::new("1111"),
+    ];
+    let dont_care = vec![Die::new("1011")];
+
+    let mut espresso: Espresso<4> = Espresso::new(dice.clone(), dont_care.clone());
+    espresso.solve();
+
+    assert_eq!(
+        espresso.cover(),
+        &Cover::new(vec![Die::new("00--"), Die::new("--11")])
+    );
+
+    let statistics = espresso.statistics();
+    assert_eq!(statistics.initial_dice, 6);
+    assert_eq!(statistics.initial_literals, 24);
+    assert_eq!(statistics.dice, 2);
+    assert_eq!(statistics.literals, 4);
+    assert_eq!(statistics.primes, 2);
+}
+
+#[test]
+pub fn many_variables() {
+    let dice: Vec<Die<24>> = vec![
+        Die::new("1-0---------------------"),
+        Die::new("1-1---------------------"),
+        Die::new("01----------------1-----"),
+        Die::new("0-----------------1----0"),
+        Die::new("00----------------1----1"),
+    ];
+
+    let mut espresso = Espresso::new(dice.clone(), Vec::new());
+    espresso.solve();
+
+    assert!(espresso.cover().covers_equal(&Cover::new(dice)));
+    assert_eq!(
+        espresso.cover(),
+        &Cover::new(vec![
+            Die::new("1-----------------------"),
+            Die::new("------------------1-----"),
+        ])
+    );
+}
+
+#[test]
+pub fn many_dice() {
+    // all minterms of x0 x1 + x2 !x3 + x4 x5 + !x6 x7, a few hundred dice with a small off set
+    let mut dice: Vec<Die<9>> = Vec::new();
+    for minterm in 0..512u32 {
+        let bit = |i: u32| minterm >> (8 - i) & 1 == 1;
+        if (bit(0) && bit(1)) || (bit(2) && !bit(3)) || (bit(4) && bit(5)) || (!bit(6) && bit(7)) {
+            dice.push(Die::new(format!("{minterm:09b}")));
+        }
+    }
+    assert!(dice.len() > 300);
+
+    let mut espresso = Espresso::new(dice.clone(), Vec::new());
+    espresso.solve();
+
+    assert!(espresso.cover().covers_equal(&Cover::new(dice)));
+    let statistics = espresso.statistics();
+    assert_eq!(statistics.dice, 4);
+    assert_eq!(statistics.literals, 8);
+}
@@ -0,0 +1,124 @@
+use consensus_solver::{Die, ExamProblem, ExamSheet, ExerciseGenerator};
+
+fn sheet() -> ExamSheet<3> {
+    let mut sheet = ExamSheet::new("Exam");
+    sheet.add_problem("on: 0 1 3\ndc: 7".parse().unwrap());
+    sheet
+}
+
+#[test]
+fn problem_sheet() {
+    assert_eq!(
+        sheet().problem_sheet(),
+        concat!(
+            "\\documentclass{article}\n",
+            "\\usepackage[utf8]{inputenc}\n",
+            "\\usepackage{amsmath, amssymb}\n",
+            "\\begin{document}\n",
+            "\\section*{Exam}\n",
+            "\n",
+            "\\subsection*{Problem 1}\n",
+            "Minimize $f(x_{1}, x_{2}, x_{3}) = \\sum m(0, 1, 3) + \\sum d(7)$.\n",
+            "\n",
+            "\\medskip\n",
+            "Consensus table:\n",
+            "\n",
+            "\\begin{tabular}{|r|l|c|l|}\n",
+            "\\hline\n",
+            "Num. & Created by & Die & Covered by \\\\\n",
+            "\\hline\\hline\n",
+            "0 & & \\texttt{(1 1 1)} & \\\\\n",
+            "\\hline\n",
+            "1 & & \\texttt{(0 0 0)} & \\\\\n",
+            "\\hline\n",
+            "2 & & \\texttt{(0 0 1)} & \\\\\n",
+            "\\hline\n",
+            "3 & & \\texttt{(0 1 1)} & \\\\\n",
+            "\\hline\n",
+            " & & & \\\\\n",
+            "\\hline\n",
+            " & & & \\\\\n",
+            "\\hline\n",
+            " & & & \\\\\n",
+            "\\hline\n",
+            "\\end{tabular}\n",
+            "\n",
+            "\\medskip\n",
+            "Quine table:\n",
+            "\n",
+            "\\begin{tabular}{|l|c|c|c|}\n",
+            "\\hline\n",
+            "Die & 0 & 1 & 3 \\\\\n",
+            "\\hline\\hline\n",
+            " & & & \\\\\n",
+            "\\hline\n",
+            " & & & \\\\\n",
+            "\\hline\n",
+            " & & & \\\\\n",
+            "\\hline\n",
+            "\\end{tabular}\n",
+            "\n",
+            "\\bigskip\n",
+            "Minimal DNF: $f = $\n",
+            "\\end{document}\n",
+        )
+    );
+}
+
+#[test]
+fn answer_key() {
+    let key = sheet().answer_key();
+
+    assert!(key.contains("\\section*{Exam -- Answer Key}\n"));
+    assert!(key.contains("6 & 5, 4 & \\texttt{(0 - 1)} &  \\\\\n"));
+    assert!(key.contains("\\texttt{(0 0 -)} & $\\circledast$ & $\\times$ &  \\\\\n"));
+    assert!(
+        key.contains("Minimal DNF: $f = \\overline{x_{1}} \\overline{x_{2}} \\lor x_{2} x_{3}$\n")
+    );
+    assert!(key.contains(concat!(
+        "$x_{1} \\setminus x_{2}x_{3}$ & 00 & 01 & 11 & 10 \\\\\n",
+        "\\hline\n",
+        "0 & 1 & 1 & 1 & 0 \\\\\n",
+        "\\hline\n",
+        "1 & 0 & 0 & - & 0 \\\\\n",
+    )));
+}
+
+#[test]
+fn escaped_title() {
+    let mut sheet: ExamSheet<2> = ExamSheet::new("Logic & Design 100% {A_1}");
+    sheet.add_problem("on: 0".parse().unwrap());
+
+    assert!(sheet
+        .problem_sheet()
+        .contains("\\section*{Logic \\& Design 100\\% \\{A\\_1\\}}\n"));
+    assert!(sheet
+        .answer_key()
+        .contains("\\section*{Logic \\& Design 100\\% \\{A\\_1\\} -- Answer Key}\n"));
+}
+
+#[test]
+fn parse_problems() {
+    let problem: ExamProblem<3> = "on: 0, 1, 3\n".parse().unwrap();
+    assert_eq!(
+        problem.on,
+        vec![Die::new("000"), Die::new("001"), Die::new("011")]
+    );
+    assert!(problem.dont_care.is_empty());
+
+    assert!("on: 8".parse::<ExamProblem<3>>().is_err());
+    assert!("dc: 1".parse::<ExamProblem<3>>().is_err());
+    assert!("off: 1".parse::<ExamProblem<3>>().is_err());
+}
+
+#[test]
+fn generated_problems() {
+    let mut sheet = ExamSheet::new("Generated");
+    for seed in 0..3 {
+        let exercise = ExerciseGenerator::<4>::new(seed).generate().unwrap();
+        sheet.add_problem(ExamProblem::from(&exercise));
+    }
+
+    assert_eq!(sheet.problems().len(), 3);
+    assert_eq!(sheet.answer_key().matches("\\subsection*").count(), 3);
+}
@@ -0,0 +1,100 @@
+use consensus_solver::{Cover, Die, ExerciseGenerator, SecondQuineTable};
+
+#[test]
+fn reproducible() {
+    let generator = ExerciseGenerator::<4>::new(42)
+        .density(0.4)
+        .dont_care_ratio(0.2);
+    let a = generator.generate().unwrap();
+    let b = generator.generate().unwrap();
+
+    assert_eq!(a.on, b.on);
+    assert_eq!(a.dont_care, b.dont_care);
+    assert_eq!(a.quine_table.to_string(), b.quine_table.to_string());
+
+    let other = ExerciseGenerator::<4>::new(43)
+        .density(0.4)
+        .dont_care_ratio(0.2);
+    assert_ne!(other.generate().unwrap().on, a.on);
+}
+
+#[test]
+fn target_properties() {
+    let exercise = ExerciseGenerator::<4>::new(1)
+        .density(0.5)
+        .cyclic_core(true)
+        .derivation_depth(2)
+        .generate()
+        .unwrap();
+
+    assert!(exercise.quine_table.has_cyclic_core());
+    assert_eq!(exercise.consensus_table.derivation_depth(), 2);
+
+    // the solution covers exactly the function
+    let solution = Cover::new(exercise.quine_table.solution());
+    let on = Cover::new(exercise.on.clone());
+    let dont_care = Cover::new(exercise.dont_care.clone());
+    assert!(solution.union(&dont_care).cover_contains(&on));
+    assert!(on.union(&dont_care).cover_contains(&solution));
+
+    // the seed of the exercise regenerates it
+    let regenerated = ExerciseGenerator::<4>::new(exercise.seed)
+        .density(0.5)
+        .max_attempts(1)
+        .generate()
+        .unwrap();
+    assert_eq!(regenerated.on, exercise.on);
+}
+
+#[test]
+fn essential_primes() {
+    let exercise = ExerciseGenerator::<3>::new(5)
+        .essential_primes(3)
+        .cyclic_core(false)
+        .generate()
+        .unwrap();
+
+    assert_eq!(exercise.quine_table.essential_rows().len(), 3);
+    assert!(!exercise.quine_table.has_cyclic_core());
+}
+
+#[test]
+fn unsolved_cyclic_core() {
+    let mut table: SecondQuineTable<3> = SecondQuineTable::from(vec![
+        Die::new("00-"),
+        Die::new("0-0"),
+        Die::new("-01"),
+        Die::new("-10"),
+        Die::new("1-1"),
+        Die::new("11-"),
+    ]);
+    assert!(!table.has_cyclic_core());
+
+    table.solve();
+    assert!(table.has_cyclic_core());
+}
+
+#[test]
+fn unique_solution() {
+    let exercise = ExerciseGenerator::<4>::new(3)
+        .cyclic_core(true)
+        .minimal_solutions(1)
+        .generate()
+        .unwrap();
+
+    assert_eq!(exercise.difficulty.minimal_solutions, Some(1));
+    assert_eq!(exercise.quine_table.solution_count(), 1);
+
+    // without a solution count target the solutions aren't enumerated
+    let uncounted = ExerciseGenerator::<4>::new(3).generate().unwrap();
+    assert_eq!(uncounted.difficulty.minimal_solutions, None);
+}
+
+#[test]
+fn impossible_targets() {
+    assert!(ExerciseGenerator::<2>::new(0)
+        .essential_primes(5)
+        .max_attempts(100)
+        .generate()
+        .is_none());
+}
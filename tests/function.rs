@@ -0,0 +1,58 @@
+use consensus_solver::{Die, Function, SecondQuineTable};
+
+#[test]
+fn sampled_sets() {
+    let function = Function::<2>::from_fn(|[a, b]| if a && b { None } else { Some(a ^ b) });
+
+    assert_eq!(function.on, vec![Die::new("01"), Die::new("10")]);
+    assert_eq!(function.off, vec![Die::new("00")]);
+    assert_eq!(function.dont_care, vec![Die::new("11")]);
+
+    // N is inferred from the closure pattern
+    let inferred = Function::from_fn(|[a, b, c]| Some(a && b && c));
+    assert_eq!(inferred.on, vec![Die::new("111")]);
+}
+
+#[test]
+#[should_panic(expected = "at most 16 variables")]
+fn too_many_variables() {
+    Function::<17>::from_fn(|_| Some(true));
+}
+
+#[test]
+fn minimal_form() {
+    // majority of three, dont caring about the inputs being all equal
+    let function = Function::<3>::from_fn(|bits| {
+        let ones = bits.iter().filter(|&&bit| bit).count();
+        match ones {
+            0 | 3 => None,
+            _ => Some(ones >= 2),
+        }
+    });
+    let mut consensus_table = function.consensus_table();
+    consensus_table.solve();
+    let mut quine_table = SecondQuineTable::from(&consensus_table);
+    quine_table.solve();
+
+    assert_eq!(
+        quine_table
+            .solution()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec!["(- 1 1)", "(1 - 1)", "(1 1 -)"]
+    );
+}
+
+#[test]
+fn overlapping_dice() {
+    let function: Function<2> = Function {
+        on: vec![Die::new("1-"), Die::new("11")],
+        off: vec![Die::new("0-")],
+        dont_care: vec![],
+    };
+
+    let mut consensus_table = function.consensus_table();
+    consensus_table.solve();
+    assert_eq!(consensus_table.prime_implicants(), vec![Die::new("1-")]);
+}
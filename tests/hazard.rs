@@ -0,0 +1,81 @@
+use consensus_solver::{ConesnsusTable, Die, StaticHazard};
+
+#[test]
+pub fn consensus_hazard() {
+    let cover = vec![Die::new("11-"), Die::new("0-1")];
+
+    let hazards: Vec<StaticHazard<3>> = StaticHazard::find(&cover);
+
+    assert_eq!(
+        hazards,
+        vec![StaticHazard {
+            transition: Die::new("-11"),
+            variable: 0,
+        }]
+    );
+    assert_eq!(hazards[0].minterms(), [Die::new("011"), Die::new("111")]);
+}
+
+#[test]
+pub fn hazard_free_cover() {
+    let dice = vec![
+        Die::new("001"),
+        Die::new("011"),
+        Die::new("110"),
+        Die::new("111"),
+    ];
+
+    let mut table: ConesnsusTable<3> = ConesnsusTable::new(dice, Vec::new());
+    table.solve();
+
+    let cover = vec![Die::new("11-"), Die::new("0-1")];
+    let hazard_free = table.hazard_free_cover(&cover);
+
+    assert_eq!(
+        hazard_free,
+        vec![Die::new("11-"), Die::new("0-1"), Die::new("-11")]
+    );
+    assert!(StaticHazard::find(&hazard_free).is_empty());
+}
+
+#[test]
+pub fn wide_consensus() {
+    // the consensus (1 - ... - 1) spans 2^16 transitions, but is reported once
+    let mut a = ["-"; 18];
+    a[0] = "1";
+    a[17] = "1";
+    let mut b = a;
+    b[0] = "0";
+    let cover: Vec<Die<18>> = vec![Die::new(a.concat()), Die::new(b.concat())];
+
+    let hazards = StaticHazard::find(&cover);
+    assert_eq!(
+        hazards,
+        vec![StaticHazard {
+            transition: Die::new(format!("{}1", "-".repeat(17))),
+            variable: 0,
+        }]
+    );
+
+    let wider: Vec<Die<70>> = vec![
+        Die::new(format!("1{}1", "-".repeat(68))),
+        Die::new(format!("0{}1", "-".repeat(68))),
+    ];
+    assert_eq!(StaticHazard::find(&wider).len(), 1);
+}
+
+#[test]
+pub fn partially_covered_consensus() {
+    // (- 1 1) covers one of the two transitions of the consensus (- - 1)
+    let cover: Vec<Die<3>> = vec![Die::new("1-1"), Die::new("0-1"), Die::new("-11")];
+    assert_eq!(
+        StaticHazard::find(&cover),
+        vec![StaticHazard {
+            transition: Die::new("--1"),
+            variable: 0,
+        }]
+    );
+
+    let covered: Vec<Die<3>> = vec![Die::new("1-1"), Die::new("0-1"), Die::new("--1")];
+    assert!(StaticHazard::find(&covered).is_empty());
+}
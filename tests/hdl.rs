@@ -0,0 +1,149 @@
+use consensus_solver::{Die, HdlLanguage, HdlModule, HdlOutput};
+
+fn module() -> HdlModule<3> {
+    HdlModule::new("example")
+        .inputs(["a", "b", "c"])
+        .output(
+            HdlOutput::new("f", vec![Die::new("00-"), Die::new("-11")]).with_truth_table(
+                vec![Die::new("000"), Die::new("001"), Die::new("011")],
+                vec![Die::new("111")],
+            ),
+        )
+        .output(HdlOutput::new("g", vec![Die::new("1-0")]))
+}
+
+#[test]
+fn verilog() {
+    assert_eq!(
+        module().render(HdlLanguage::Verilog),
+        concat!(
+            "module example (\n",
+            "    input wire a,\n",
+            "    input wire b,\n",
+            "    input wire c,\n",
+            "    output wire f,\n",
+            "    output reg f_table,\n",
+            "    output wire g\n",
+            ");\n",
+            "    assign f = (~a & ~b) | (b & c);\n",
+            "    assign g = a & ~c;\n",
+            "\n",
+            "    always @* begin\n",
+            "        case ({a, b, c})\n",
+            "            3'b000: f_table = 1'b1;\n",
+            "            3'b001: f_table = 1'b1;\n",
+            "            3'b011: f_table = 1'b1;\n",
+            "            3'b111: f_table = 1'bx;\n",
+            "            default: f_table = 1'b0;\n",
+            "        endcase\n",
+            "    end\n",
+            "endmodule\n",
+        )
+    );
+}
+
+#[test]
+fn system_verilog() {
+    let module = module().render(HdlLanguage::SystemVerilog);
+
+    assert!(module.contains("    input logic a,\n"));
+    assert!(module.contains("    output logic f_table,\n"));
+    assert!(module.contains("    always_comb begin\n"));
+}
+
+#[test]
+fn vhdl() {
+    assert_eq!(
+        module().render(HdlLanguage::Vhdl),
+        concat!(
+            "library ieee;\n",
+            "use ieee.std_logic_1164.all;\n",
+            "\n",
+            "entity example is\n",
+            "    port (\n",
+            "        a : in std_logic;\n",
+            "        b : in std_logic;\n",
+            "        c : in std_logic;\n",
+            "        f : out std_logic;\n",
+            "        f_table : out std_logic;\n",
+            "        g : out std_logic\n",
+            "    );\n",
+            "end entity example;\n",
+            "\n",
+            "architecture rtl of example is\n",
+            "begin\n",
+            "    f <= (not a and not b) or (b and c);\n",
+            "    g <= a and not c;\n",
+            "\n",
+            "    process (a, b, c)\n",
+            "        variable inputs : std_logic_vector(2 downto 0);\n",
+            "    begin\n",
+            "        inputs := a & b & c;\n",
+            "        case inputs is\n",
+            "            when \"000\" => f_table <= '1';\n",
+            "            when \"001\" => f_table <= '1';\n",
+            "            when \"011\" => f_table <= '1';\n",
+            "            when \"111\" => f_table <= '-';\n",
+            "            when others => f_table <= '0';\n",
+            "        end case;\n",
+            "    end process;\n",
+            "end architecture rtl;\n",
+        )
+    );
+}
+
+#[test]
+fn constants() {
+    let module = HdlModule::<2>::new("constants")
+        .output(HdlOutput::new("zero", vec![]))
+        .output(HdlOutput::new("one", vec![Die::new("--")]));
+
+    let verilog = module.render(HdlLanguage::Verilog);
+    assert!(verilog.contains("    input wire x1,\n"));
+    assert!(verilog.contains("    assign zero = 1'b0;\n"));
+    assert!(verilog.contains("    assign one = 1'b1;\n"));
+
+    let vhdl = module.render(HdlLanguage::Vhdl);
+    assert!(vhdl.contains("    zero <= '0';\n"));
+    assert!(vhdl.contains("    one <= '1';\n"));
+}
+
+#[test]
+#[should_panic(expected = "not a valid identifier")]
+fn invalid_identifier() {
+    HdlModule::<2>::new("2fast");
+}
+
+#[test]
+#[should_panic(expected = "reserved word")]
+fn verilog_keyword() {
+    HdlModule::<2>::new("module");
+}
+
+#[test]
+#[should_panic(expected = "reserved word")]
+fn vhdl_keyword() {
+    HdlModule::<2>::new("example").inputs(["a", "Signal"]);
+}
+
+#[test]
+#[should_panic(expected = "\"a\" is used for more than one input or output")]
+fn input_output_collision() {
+    HdlModule::<2>::new("example")
+        .inputs(["a", "b"])
+        .output(HdlOutput::new("a", vec![]));
+}
+
+#[test]
+#[should_panic(expected = "\"f_table\" is used for more than one input or output")]
+fn table_collision() {
+    HdlModule::<2>::new("example")
+        .output(HdlOutput::new("f_table", vec![]))
+        .output(HdlOutput::new("f", vec![]).with_truth_table(vec![], vec![]));
+}
+
+#[test]
+#[should_panic(expected = "\"B\" is used for more than one input or output")]
+fn case_insensitive_collision() {
+    HdlModule::<2>::new("example").inputs(["b", "B"]);
+}
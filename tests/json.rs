@@ -0,0 +1,143 @@
+#![cfg(feature = "serde")]
+
+use consensus_solver::{ConesnsusTable, Cover, Die, DieSide, SecondQuineTable};
+
+#[test]
+fn dice() {
+    let die: Die<3> = Die::new("10-");
+    assert_eq!(serde_json::to_string(&die).unwrap(), "\"10-\"");
+    assert_eq!(serde_json::from_str::<Die<3>>("\"10-\"").unwrap(), die);
+    assert_eq!(serde_json::to_string(&DieSide::DontCare).unwrap(), "\"-\"");
+
+    assert!(serde_json::from_str::<Die<3>>("\"10\"").is_err());
+    assert!(serde_json::from_str::<Die<3>>("\"10x\"").is_err());
+}
+
+#[test]
+fn consensus_table() {
+    let mut table: ConesnsusTable<3> =
+        ConesnsusTable::new(vec![Die::new("11-"), Die::new("0-1")], vec![]);
+    table.solve();
+
+    let json = serde_json::to_value(&table).unwrap();
+    assert_eq!(
+        json["entries"][2],
+        serde_json::json!({
+            "num": 2,
+            "creators": [1, 0],
+            "die": "-11",
+            "covered": null,
+            "dont_care": false,
+        })
+    );
+
+    let imported: ConesnsusTable<3> = serde_json::from_value(json).unwrap();
+    assert_eq!(imported.to_string(), table.to_string());
+}
+
+#[test]
+fn invalid_consensus_table() {
+    let entry = |num: usize, creators: Option<[usize; 2]>, covered: Option<usize>| {
+        serde_json::json!({
+            "num": num,
+            "creators": creators,
+            "die": "11-",
+            "covered": covered,
+            "dont_care": false,
+        })
+    };
+
+    let valid =
+        serde_json::json!({ "entries": [entry(0, None, None), entry(1, Some([0, 0]), Some(0))] });
+    assert!(serde_json::from_value::<ConesnsusTable<3>>(valid.clone()).is_ok());
+
+    let unordered = serde_json::json!({ "entries": [entry(1, None, None), entry(0, None, None)] });
+    assert!(serde_json::from_value::<ConesnsusTable<3>>(unordered).is_err());
+
+    let unknown_cover = serde_json::json!({ "entries": [entry(0, None, Some(5))] });
+    assert!(serde_json::from_value::<ConesnsusTable<3>>(unknown_cover).is_err());
+
+    let later_creator =
+        serde_json::json!({ "entries": [entry(0, Some([0, 1]), None), entry(1, None, None)] });
+    assert!(serde_json::from_value::<ConesnsusTable<3>>(later_creator).is_err());
+
+    let mut unnumbered = valid;
+    unnumbered["entries"][1]["num"] = serde_json::Value::Null;
+    let error = serde_json::from_value::<ConesnsusTable<3>>(unnumbered)
+        .err()
+        .unwrap();
+    assert_eq!(error.to_string(), "entry 1 has no number");
+}
+
+#[test]
+fn quine_table() {
+    let mut table: SecondQuineTable<3> = SecondQuineTable::from(vec![
+        Die::new("00-"),
+        Die::new("0-0"),
+        Die::new("-01"),
+        Die::new("-10"),
+        Die::new("1-1"),
+        Die::new("11-"),
+        Die::new("-11"),
+    ]);
+    table.solve();
+
+    let json = serde_json::to_value(&table).unwrap();
+    assert_eq!(json["numbering"], serde_json::json!([2, 1, 0]));
+    assert_eq!(
+        json["columns"][0],
+        serde_json::json!({ "term": "0", "removed": { "round": 2, "reason": { "covered": 0 } } })
+    );
+    assert_eq!(
+        json["rows"][2],
+        serde_json::json!({
+            "die": "-01",
+            "cells": [null, "dominating_column", null, null, "essential", null, null],
+            "removed": { "round": 1, "reason": "essential" },
+        })
+    );
+    assert_eq!(
+        json["rows"][1]["removed"],
+        serde_json::json!({ "round": 1, "reason": { "dominated": 0 } })
+    );
+
+    let imported: SecondQuineTable<3> = serde_json::from_value(json).unwrap();
+    assert_eq!(imported.to_string(), table.to_string());
+    assert_eq!(imported.solution_rows(), table.solution_rows());
+    let rounds: Vec<String> = table.reduction_rounds().map(|r| r.to_string()).collect();
+    let imported_rounds: Vec<String> = imported.reduction_rounds().map(|r| r.to_string()).collect();
+    assert_eq!(imported_rounds, rounds);
+}
+
+#[test]
+fn invalid_quine_table() {
+    let json = serde_json::json!({
+        "numbering": [2, 1, 0],
+        "columns": [{ "term": "1", "removed": null }],
+        "rows": [{ "die": "000", "cells": ["na"], "removed": null }],
+        "solutions": [],
+        "cost": null,
+        "rounds": 0,
+    });
+    assert!(serde_json::from_value::<SecondQuineTable<3>>(json).is_err());
+}
+
+#[test]
+fn covers() {
+    let cover: Cover<3> = Cover::new(vec![Die::new("1--"), Die::new("-01")]);
+    let json = serde_json::to_string(&cover).unwrap();
+    assert_eq!(json, r#"{"dice":["1--","-01"]}"#);
+    assert_eq!(serde_json::from_str::<Cover<3>>(&json).unwrap(), cover);
+}
+
+#[test]
+fn large_minterms() {
+    let die: Die<70> = Die::new(format!("1{}", "0".repeat(69)));
+    let table: SecondQuineTable<70> = SecondQuineTable::from(vec![die]);
+
+    let json = serde_json::to_value(&table).unwrap();
+    assert_eq!(json["columns"][0]["term"], "590295810358705651712");
+
+    let imported: SecondQuineTable<70> = serde_json::from_value(json).unwrap();
+    assert_eq!(imported.terms(), table.terms());
+}
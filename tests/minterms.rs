@@ -0,0 +1,51 @@
+use consensus_solver::{Die, Minterm, SecondQuineTable};
+
+#[test]
+fn minterms() {
+    let die: Die<4> = Die::new("1-0-");
+
+    assert_eq!(die.minterm_count(), 4);
+    assert_eq!(die.minterms().collect::<Vec<Minterm>>(), vec![8, 9, 12, 13]);
+    assert!(die.covers_minterm(12));
+    assert!(!die.covers_minterm(10));
+}
+
+#[test]
+fn minterm_edge_cases() {
+    let universe: Die<3> = Die::new("---");
+    assert_eq!(
+        universe.minterms().collect::<Vec<Minterm>>(),
+        (0..8).collect::<Vec<Minterm>>()
+    );
+
+    let term: Die<3> = Die::new("101");
+    assert_eq!(term.minterm_count(), 1);
+    assert_eq!(term.minterms().collect::<Vec<Minterm>>(), vec![5]);
+
+    let wide: Die<40> = Die::new(format!("1{}", "-".repeat(39)));
+    assert_eq!(wide.minterm_count(), 1 << 39);
+    assert_eq!(wide.minterms().nth(3), Some((1 << 39) + 3));
+}
+
+#[test]
+fn wide_minterms() {
+    let high: Die<100> = Die::new(format!("1{}-", "0".repeat(98)));
+    let low: Die<100> = Die::new(format!("{}1", "0".repeat(99)));
+    assert_eq!(
+        high.minterms().collect::<Vec<Minterm>>(),
+        vec![1 << 99, (1 << 99) + 1]
+    );
+
+    let mut table = SecondQuineTable::from(vec![high, low]);
+    table.solve();
+    assert!(table.covers(0, (1 << 99) + 1));
+    assert!(table.covers(1, 1));
+    assert_eq!(table.solution_rows(), vec![0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "more than 128 variables")]
+fn too_wide_minterms() {
+    let die: Die<130> = Die::new("0".repeat(130));
+    die.minterms().next();
+}
@@ -0,0 +1,78 @@
+use consensus_solver::{Die, Minterm, Numbering, SecondQuineTable, SecondQuineTableEntry};
+
+#[test]
+fn conventions() {
+    let die: Die<3> = Die::new("10-");
+
+    let msb = Numbering::msb_first();
+    let lsb = Numbering::lsb_first();
+    let permuted = Numbering::permutation([2, 0, 1]);
+
+    assert_eq!(msb.minterms(&die).collect::<Vec<Minterm>>(), vec![4, 5]);
+    assert_eq!(lsb.minterms(&die).collect::<Vec<Minterm>>(), vec![1, 5]);
+    assert_eq!(
+        permuted.minterms(&die).collect::<Vec<Minterm>>(),
+        vec![2, 6]
+    );
+
+    assert_eq!(msb.die(6), Die::new("110"));
+    assert_eq!(lsb.die(6), Die::new("011"));
+    assert_eq!(permuted.die(6), Die::new("101"));
+    assert_eq!(Die::<3>::from_minterm(1), Die::new("001"));
+    assert!(lsb.covers(&die, 5));
+    assert!(!lsb.covers(&die, 4));
+}
+
+#[test]
+fn on_minterms() {
+    let on: Vec<Die<3>> = vec![Die::new("1--"), Die::new("-01")];
+    let dont_care = vec![Die::new("11-")];
+
+    let msb = Numbering::msb_first();
+    assert_eq!(msb.cover_minterms(&on), vec![1, 4, 5, 6, 7]);
+    assert_eq!(msb.on_minterms(&on, &dont_care), vec![1, 4, 5]);
+    assert_eq!(
+        Numbering::lsb_first().on_minterms(&on, &dont_care),
+        vec![1, 4, 5]
+    );
+}
+
+#[test]
+#[should_panic(expected = "exactly once")]
+fn invalid_permutation() {
+    Numbering::permutation([0, 2, 0]);
+}
+
+#[test]
+fn quine_table_headers() {
+    let dice = vec![Die::new("1-0"), Die::new("-11")];
+    let table: SecondQuineTable<3> =
+        SecondQuineTable::with_numbering(dice, vec![], Numbering::lsb_first());
+
+    assert_eq!(table.numbering(), Numbering::lsb_first());
+    assert!(table.covers(0, 3));
+    assert!(table.covers(1, 6));
+    assert_eq!(
+        table.to_string(),
+        concat!(
+            " Die     ┃ 1 ┃ 3 ┃ 6 ┃ 7 \n",
+            "━━━━━━━━━╋━━━╋━━━╋━━━╋━━━\n",
+            " (1 - 0) ┃ X ┃ X ┃   ┃   \n",
+            " (- 1 1) ┃   ┃   ┃ X ┃ X \n",
+        )
+    );
+}
+
+#[test]
+fn entry_terms() {
+    let die: Die<3> = Die::new("10-");
+
+    let lsb = SecondQuineTableEntry::with_numbering(die, Numbering::lsb_first());
+    assert_eq!(lsb.terms().collect::<Vec<Minterm>>(), vec![1, 5]);
+    assert!(lsb.covers(1));
+    assert!(!lsb.covers(4));
+
+    let msb = SecondQuineTableEntry::from(die);
+    assert_eq!(msb.numbering(), Numbering::msb_first());
+    assert_eq!(msb.terms().collect::<Vec<Minterm>>(), vec![4, 5]);
+}
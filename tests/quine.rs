@@ -1,4 +1,6 @@
-use consensus_solver::{SecondQuineTable, SecondQuineTableEntry, Die};
+use consensus_solver::{
+    Die, GateInputCount, LiteralCount, LiteralWeights, SecondQuineTable, SecondQuineTableEntry,
+};
 
 #[test]
 fn testt() {
@@ -11,4 +13,175 @@ fn testt() {
     ]);
 
     println!("{table}");
-}
\ No newline at end of file
+}
+
+#[test]
+fn cyclic_core() {
+    let mut table: SecondQuineTable<3> = SecondQuineTable::from(vec![
+        Die::new("00-"),
+        Die::new("0-0"),
+        Die::new("-01"),
+        Die::new("-10"),
+        Die::new("1-1"),
+        Die::new("11-"),
+    ]);
+
+    table.solve();
+    assert_eq!(table.optimal_cost(), Some(3));
+    assert_eq!(table.solution_rows(), &[0, 3, 4]);
+
+    table.solve_with(&GateInputCount);
+    assert_eq!(table.optimal_cost(), Some(9));
+}
+
+#[test]
+fn single_product_gate_inputs() {
+    // a single product drives the output directly, so there is no OR gate
+    let mut table: SecondQuineTable<3> = SecondQuineTable::from(vec![Die::new("11-")]);
+    table.solve_with(&GateInputCount);
+    assert_eq!(table.optimal_cost(), Some(2));
+
+    let mut table: SecondQuineTable<3> = SecondQuineTable::from(vec![Die::new("-0-")]);
+    table.solve_with(&GateInputCount);
+    assert_eq!(table.optimal_cost(), Some(0));
+}
+
+#[test]
+fn cost_functions() {
+    let mut table: SecondQuineTable<4> = SecondQuineTable::from(vec![
+        Die::new("0-0-"),
+        Die::new("-00-"),
+        Die::new("--01"),
+        Die::new("10--"),
+    ]);
+
+    table.solve_with(&LiteralCount);
+    assert_eq!(table.optimal_cost(), Some(6));
+    assert_eq!(
+        table.solution(),
+        vec![Die::new("0-0-"), Die::new("--01"), Die::new("10--")]
+    );
+
+    table.solve_with(&LiteralWeights {
+        weights: [5, 1, 1, 1],
+    });
+    assert_eq!(table.optimal_cost(), Some(14));
+    assert_eq!(
+        table.solution(),
+        vec![Die::new("0-0-"), Die::new("--01"), Die::new("10--")]
+    );
+}
+
+#[test]
+fn all_minimal_solutions() {
+    let mut table: SecondQuineTable<3> = SecondQuineTable::from(vec![
+        Die::new("00-"),
+        Die::new("0-0"),
+        Die::new("-01"),
+        Die::new("-10"),
+        Die::new("1-1"),
+        Die::new("11-"),
+    ]);
+
+    table.solve_all(10);
+    assert_eq!(table.solution_count(), 2);
+    assert_eq!(
+        table.solutions().collect::<Vec<_>>(),
+        vec![
+            vec![Die::new("00-"), Die::new("-10"), Die::new("1-1")],
+            vec![Die::new("0-0"), Die::new("-01"), Die::new("11-")],
+        ]
+    );
+
+    table.solve_all(1);
+    assert_eq!(table.solution_count(), 1);
+
+    table.solve_all(0);
+    assert_eq!(table.solution_count(), 0);
+    assert_eq!(table.solution(), Vec::new());
+    assert_eq!(table.optimal_cost(), Some(3));
+}
+
+#[test]
+fn reduction_rounds() {
+    let mut table: SecondQuineTable<3> = SecondQuineTable::from(vec![
+        Die::new("00-"),
+        Die::new("0-0"),
+        Die::new("-01"),
+        Die::new("-10"),
+        Die::new("1-1"),
+        Die::new("11-"),
+        Die::new("-11"),
+    ]);
+    table.solve();
+
+    assert_eq!(table.rounds(), 3);
+    let rounds: Vec<String> = table
+        .reduction_rounds()
+        .map(|round| round.to_string())
+        .collect();
+    assert_eq!(
+        rounds[1],
+        concat!(
+            "Round 2\n",
+            " Die     ┃ 0 ┃ 5 ┃ 6 ┃ Reason\n",
+            "━━━━━━━━━╋━━━╋━━━╋━━━╋━━━━━━━\n",
+            " (0 0 -) ┃ X ┃   ┃   ┃ \n",
+            " (0 - 0) ┃ ─ ┃   ┃   ┃ dominated by (0 0 -)\n",
+            " (- 0 1) ┃   ┃ ⊛ ┃   ┃ essential\n",
+            " (- 1 0) ┃   ┃   ┃ ⊛ ┃ essential\n",
+            " 5 covered by (- 0 1)\n",
+            " 6 covered by (- 1 0)\n",
+        )
+    );
+}
+
+#[test]
+fn empty_row() {
+    let mut table: SecondQuineTable<2> =
+        SecondQuineTable::from(vec![Die::new("0-"), Die::new("-0"), Die::new("00")]);
+    table.solve();
+
+    assert_eq!(table.rounds(), 1);
+    assert_eq!(
+        table.reduction_rounds().next().unwrap().to_string(),
+        concat!(
+            "Round 1\n",
+            " Die   ┃ 0 ┃ 1 ┃ 2 ┃ Reason\n",
+            "━━━━━━━╋━━━╋━━━╋━━━╋━━━━━━━\n",
+            " (0 -) ┃ X ┃ ⊛ ┃   ┃ essential\n",
+            " (- 0) ┃ │ ┃   ┃ ⊛ ┃ essential\n",
+            " (0 0) ┃ │ ┃   ┃   ┃ nothing left to cover\n",
+            " 0 covered by (0 -)\n",
+            " 1 covered by (0 -)\n",
+            " 2 covered by (- 0)\n",
+        )
+    );
+}
+
+#[test]
+fn sparse_terms() {
+    // the terms 0, 11, 13 and 15 are no contiguous range, so they must be mapped to their columns
+    let mut table: SecondQuineTable<4> =
+        SecondQuineTable::from(vec![Die::new("1-11"), Die::new("11-1"), Die::new("0000")]);
+    table.solve();
+
+    assert!(table.covers(0, 15));
+    assert!(!table.covers(2, 15));
+    assert!(!table.covers(0, 4));
+    assert_eq!(table.solution_rows(), vec![0, 1, 2]);
+}
+
+#[test]
+fn large_table() {
+    let dice: Vec<Die<12>> = (0..4096)
+        .filter(|term: &usize| term.count_ones() % 3 == 0)
+        .map(|term| Die::new(format!("{:012b}", term)))
+        .collect();
+    let count = dice.len();
+    let mut table = SecondQuineTable::from(dice);
+    table.solve();
+
+    assert_eq!(table.solution_rows().len(), count);
+    assert_eq!(table.rounds(), 1);
+}
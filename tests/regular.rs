@@ -1,19 +1,19 @@
-use consensus_solver::{self, ConesnsusTable, ConsensusTableEntry, Die};
+use consensus_solver::{self, die, ConesnsusTable, ConsensusTableEntry};
 
 #[test]
 pub fn test1() {
     let mut dice = Vec::new();
     let mut dont_cares = Vec::new();
 
-    dice.push(Die::new("01100"));
-    dice.push(Die::new("01101"));
-    dice.push(Die::new("01110"));
-    dice.push(Die::new("01111"));
-    dice.push(Die::new("11101"));
-    dice.push(Die::new("11110"));
+    dice.push(die!("01100"));
+    dice.push(die!("01101"));
+    dice.push(die!("01110"));
+    dice.push(die!("01111"));
+    dice.push(die!("11101"));
+    dice.push(die!("11110"));
 
-    dont_cares.push(Die::new("10001"));
-    dont_cares.push(Die::new("10010"));
+    dont_cares.push(die!("10001"));
+    dont_cares.push(die!("10010"));
 
     let mut table: ConesnsusTable<5> = ConesnsusTable::new(dice, dont_cares);
     table.solve();
@@ -22,91 +22,91 @@ pub fn test1() {
         ConsensusTableEntry {
             num: Some(0),
             creators: None,
-            die: Die::new("10001"),
+            die: die!("10001"),
             covered: Some(0),
             dont_care: true,
         },
         ConsensusTableEntry {
             num: Some(1),
             creators: None,
-            die: Die::new("10010"),
+            die: die!("10010"),
             covered: Some(1),
             dont_care: true,
         },
         ConsensusTableEntry {
             num: Some(2),
             creators: None,
-            die: Die::new("01100"),
+            die: die!("01100"),
             covered: Some(8),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(3),
             creators: None,
-            die: Die::new("01101"),
+            die: die!("01101"),
             covered: Some(8),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(4),
             creators: None,
-            die: Die::new("01110"),
+            die: die!("01110"),
             covered: Some(9),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(5),
             creators: None,
-            die: Die::new("01111"),
+            die: die!("01111"),
             covered: Some(9),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(6),
             creators: None,
-            die: Die::new("11101"),
+            die: die!("11101"),
             covered: Some(10),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(7),
             creators: None,
-            die: Die::new("11110"),
+            die: die!("11110"),
             covered: Some(11),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(8),
             creators: Some([3, 2]),
-            die: Die::new("0110-"),
+            die: die!("0110-"),
             covered: Some(12),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(9),
             creators: Some([5, 4]),
-            die: Die::new("0111-"),
+            die: die!("0111-"),
             covered: Some(12),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(10),
             creators: Some([8, 6]),
-            die: Die::new("-1101"),
+            die: die!("-1101"),
             covered: None,
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(11),
             creators: Some([9, 7]),
-            die: Die::new("-1110"),
+            die: die!("-1110"),
             covered: None,
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(12),
             creators: Some([9, 8]),
-            die: Die::new("011--"),
+            die: die!("011--"),
             covered: None,
             dont_care: false,
         },
@@ -117,14 +117,14 @@ pub fn test1() {
 
 #[test]
 pub fn test2() {
-    let mut dice = Vec::new();
-
-    dice.push(Die::new("01100"));
-    dice.push(Die::new("01101"));
-    dice.push(Die::new("01110"));
-    dice.push(Die::new("01111"));
-    dice.push(Die::new("11101"));
-    dice.push(Die::new("11110"));
+    let dice = vec![
+        die!("01100"),
+        die!("01101"),
+        die!("01110"),
+        die!("01111"),
+        die!("11101"),
+        die!("11110"),
+    ];
 
     let mut table: ConesnsusTable<5> = ConesnsusTable::new(dice, Vec::new());
     table.solve();
@@ -133,77 +133,77 @@ pub fn test2() {
         ConsensusTableEntry {
             num: Some(0),
             creators: None,
-            die: Die::new("01100"),
+            die: die!("01100"),
             covered: Some(6),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(1),
             creators: None,
-            die: Die::new("01101"),
+            die: die!("01101"),
             covered: Some(6),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(2),
             creators: None,
-            die: Die::new("01110"),
+            die: die!("01110"),
             covered: Some(7),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(3),
             creators: None,
-            die: Die::new("01111"),
+            die: die!("01111"),
             covered: Some(7),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(4),
             creators: None,
-            die: Die::new("11101"),
+            die: die!("11101"),
             covered: Some(8),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(5),
             creators: None,
-            die: Die::new("11110"),
+            die: die!("11110"),
             covered: Some(9),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(6),
             creators: Some([1, 0]),
-            die: Die::new("0110-"),
+            die: die!("0110-"),
             covered: Some(10),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(7),
             creators: Some([3, 2]),
-            die: Die::new("0111-"),
+            die: die!("0111-"),
             covered: Some(10),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(8),
             creators: Some([6, 4]),
-            die: Die::new("-1101"),
+            die: die!("-1101"),
             covered: None,
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(9),
             creators: Some([7, 5]),
-            die: Die::new("-1110"),
+            die: die!("-1110"),
             covered: None,
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(10),
             creators: Some([7, 6]),
-            die: Die::new("011--"),
+            die: die!("011--"),
             covered: None,
             dont_care: false,
         },
@@ -217,9 +217,9 @@ pub fn used_merged_dont_care() {
     let mut dice = Vec::new();
     let mut dont_cares = Vec::new();
 
-    dice.push(Die::new("-1000"));
-    dont_cares.push(Die::new("00000"));
-    dont_cares.push(Die::new("10000"));
+    dice.push(die!("-1000"));
+    dont_cares.push(die!("00000"));
+    dont_cares.push(die!("10000"));
 
     let mut table: ConesnsusTable<5> = ConesnsusTable::new(dice, dont_cares);
     table.solve();
@@ -228,35 +228,35 @@ pub fn used_merged_dont_care() {
         ConsensusTableEntry {
             num: Some(0),
             creators: None,
-            die: Die::new("00000"),
+            die: die!("00000"),
             covered: Some(3),
             dont_care: true,
         },
         ConsensusTableEntry {
             num: Some(1),
             creators: None,
-            die: Die::new("10000"),
+            die: die!("10000"),
             covered: Some(3),
             dont_care: true,
         },
         ConsensusTableEntry {
             num: Some(2),
             creators: None,
-            die: Die::new("-1000"),
+            die: die!("-1000"),
             covered: Some(4),
             dont_care: false,
         },
         ConsensusTableEntry {
             num: Some(3),
             creators: Some([1, 0]),
-            die: Die::new("-0000"),
+            die: die!("-0000"),
             covered: Some(4),
             dont_care: true,
         },
         ConsensusTableEntry {
             num: Some(4),
             creators: Some([3, 2]),
-            die: Die::new("--000"),
+            die: die!("--000"),
             covered: None,
             dont_care: false,
         },
@@ -264,3 +264,14 @@ pub fn used_merged_dont_care() {
 
     assert!(compare.to_string() == table.to_string());
 }
+
+#[test]
+pub fn covered_entry() {
+    // the consensus of the first two dice covers the third one, before it is looked at
+    let dice = vec![die!("0-0"), die!("0-1"), die!("001")];
+
+    let mut table: ConesnsusTable<3> = ConesnsusTable::new(dice, Vec::new());
+    table.solve();
+
+    assert_eq!(table.prime_implicants(), vec![die!("0--")]);
+}
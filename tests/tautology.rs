@@ -0,0 +1,53 @@
+use consensus_solver::{ConesnsusTable, Cover, Die};
+
+#[test]
+pub fn tautology() {
+    let cover: Cover<3> = Cover::new(vec![
+        Die::new("1--"),
+        Die::new("01-"),
+        Die::new("0-1"),
+        Die::new("000"),
+    ]);
+
+    assert!(cover.is_tautology());
+    assert!(
+        !Cover::<3>::new(vec![Die::new("1--"), Die::new("01-"), Die::new("0-1")]).is_tautology()
+    );
+}
+
+#[test]
+pub fn containment() {
+    let a: Cover<24> = Cover::new(vec![
+        Die::new("1-----------------------"),
+        Die::new("-1----------------------"),
+    ]);
+    let b: Cover<24> = Cover::new(vec![
+        Die::new("11----------------------"),
+        Die::new("10----------------------"),
+        Die::new("01---------------------1"),
+    ]);
+
+    assert!(a.cover_contains(&b));
+    assert!(!b.cover_contains(&a));
+    assert!(a.covers_equal(&Cover::new(vec![
+        Die::new("1-----------------------"),
+        Die::new("01----------------------"),
+    ])));
+}
+
+#[test]
+pub fn table_equivalence() {
+    let mut table: ConesnsusTable<5> = ConesnsusTable::new(
+        vec![
+            Die::new("01100"),
+            Die::new("01101"),
+            Die::new("01110"),
+            Die::new("01111"),
+            Die::new("11101"),
+        ],
+        vec![Die::new("11100")],
+    );
+    table.solve();
+
+    assert!(table.is_equivalent_to_input());
+}
@@ -0,0 +1,117 @@
+use consensus_solver::{AnswerValidator, Die, Expression, LiteralCount, Verdict};
+
+fn validator() -> AnswerValidator<3> {
+    // f = Σm(0, 1, 3) + d(7)
+    AnswerValidator::new(
+        vec![Die::new("000"), Die::new("001"), Die::new("011")],
+        vec![Die::new("111")],
+    )
+}
+
+#[test]
+fn minimal_answer() {
+    let verdict = validator().check_expression("!x1 !x2 + x2 x3").unwrap();
+
+    assert!(verdict.is_valid());
+    assert!(verdict.is_prime());
+    assert!(verdict.is_irredundant());
+    assert!(verdict.is_minimal());
+    assert_eq!(
+        verdict.to_string(),
+        concat!(
+            "Covers ON-set:  yes\n",
+            "Avoids OFF-set: yes\n",
+            "Primes:         yes\n",
+            "Irredundant:    yes\n",
+            "Minimal:        yes, cost 2 of 2\n",
+        )
+    );
+}
+
+#[test]
+fn wrong_answer() {
+    let verdict = validator().check(&[Die::new("00-"), Die::new("1-1")]);
+
+    assert_eq!(verdict.uncovered, vec![Die::new("011")]);
+    assert_eq!(verdict.off_set, vec![Die::new("101")]);
+    assert!(!verdict.is_valid());
+    assert!(!verdict.is_prime());
+    assert!(!verdict.is_minimal());
+}
+
+#[test]
+fn non_prime_and_redundant() {
+    let verdict = validator().check(&[Die::new("00-"), Die::new("011"), Die::new("0-1")]);
+
+    assert!(verdict.is_valid());
+    assert_eq!(verdict.non_primes, vec![(Die::new("011"), Die::new("-11"))]);
+    assert_eq!(verdict.redundant, vec![Die::new("011"), Die::new("0-1")]);
+    assert!(!verdict.is_minimal());
+    assert_eq!(
+        verdict.to_string(),
+        concat!(
+            "Covers ON-set:  yes\n",
+            "Avoids OFF-set: yes\n",
+            "Primes:         no, (0 1 1) ⊂ (- 1 1)\n",
+            "Irredundant:    no, (0 1 1), (0 - 1) can be removed\n",
+            "Minimal:        no, cost 3 of 2\n",
+        )
+    );
+}
+
+#[test]
+fn cost_functions() {
+    let verdict = validator().check_with(&[Die::new("00-"), Die::new("0-1")], &LiteralCount);
+
+    assert!(verdict.is_valid());
+    assert_eq!(verdict.cost, 4);
+    assert_eq!(verdict.optimal_cost, Some(4));
+    assert!(verdict.is_minimal());
+
+    // the cost function doesn't change the cached optimal cost of the fewest products
+    let verdict = validator()
+        .check_expression_with("!x1 !x2 + x2 x3", &LiteralCount)
+        .unwrap();
+    assert_eq!(verdict.optimal_cost, Some(4));
+    assert_eq!(validator().check(&[Die::new("00-")]).optimal_cost, Some(2));
+}
+
+#[test]
+fn unknown_optimal_cost() {
+    let verdict = Verdict::<3> {
+        uncovered: vec![],
+        off_set: vec![],
+        non_primes: vec![],
+        redundant: vec![],
+        cost: 2,
+        optimal_cost: None,
+    };
+
+    assert!(verdict.is_valid());
+    assert!(!verdict.is_minimal());
+    assert!(verdict
+        .to_string()
+        .ends_with("Minimal:        unknown, cost 2, no minimal cover was found\n"));
+}
+
+#[test]
+fn expressions() {
+    let expression: Expression<3> = "x1 !x2 + x_2 & x_{3}' + ~x3 * x1'".parse().unwrap();
+    assert_eq!(
+        expression.products,
+        vec![Die::new("10-"), Die::new("-10"), Die::new("0-0")]
+    );
+    assert_eq!(expression.to_string(), "x1 !x2 + x2 !x3 + !x1 !x3");
+
+    assert_eq!(
+        "1".parse::<Expression<2>>().unwrap().products,
+        vec![Die::new("--")]
+    );
+    assert!("0".parse::<Expression<2>>().unwrap().products.is_empty());
+
+    assert!("x4".parse::<Expression<3>>().is_err());
+    assert!("x1 !x1".parse::<Expression<3>>().is_err());
+    assert!("x1 +".parse::<Expression<3>>().is_err());
+    assert!("y1".parse::<Expression<3>>().is_err());
+    assert!(validator().check_expression("x1 x").is_err());
+}